@@ -83,7 +83,9 @@ pub mod bonding_curve {
         token::mint_to(cpi_ctx, 1)?;
 
         curve.current_supply += 1;
-        curve.total_volume += current_price;
+        curve.total_volume = curve.total_volume
+            .checked_add(current_price)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
         
         Ok(())
     }
@@ -1,17 +1,41 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, FreezeAccount, MintTo, ThawAccount, Token2022, TokenInterface};
 use anchor_spl::associated_token::AssociatedToken;
+use solana_sha256_hasher::hashv;
 
 // Note: For production, integrate with Metaplex Token Metadata program
 // Currently using simplified minting without metadata accounts
 
 declare_id!("BC11111111111111111111111111111111111111111");
 
+// Lamports sent here are burned at the end of the slot
+pub const INCINERATOR_ID: Pubkey = pubkey!("1nc1nerator11111111111111111111111111111111");
+
+// Native program that verifies ed25519 signatures (used for mint permits)
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+// Most recipients a royalty split may pay
+pub const MAX_ROYALTY_RECIPIENTS: usize = 5;
+
+// Fixed-point scale (1e12) for non-linear curve math
+pub const FIXED_POINT_SCALE: u128 = 1_000_000_000_000;
+
+// Prices held by each paginated Bezier lookup chunk
+pub const BEZIER_CHUNK_SIZE: u32 = 1000;
+
+// Upper bound on max_supply
+pub const MAX_SUPPLY_CAP: u32 = 1_000_000;
+
+// Most editions priced one at a time when summing a curve with no closed form
+pub const MAX_PRICE_SUM_SPAN: u32 = 1000;
+
 #[program]
 pub mod bonding_curve {
     use super::*;
 
     /// Initialize a new bonding curve for an NFT collection
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_curve(
         ctx: Context<InitializeCurve>,
         curve_type: CurveType,
@@ -20,7 +44,23 @@ pub mod bonding_curve {
         max_supply: u32,
         bezier_min_price: Option<u64>,
         bezier_max_price: Option<u64>,
+        supply_offset: u32,
     ) -> Result<()> {
+        // A zero increment is flat pricing; that is what Constant is for
+        require!(
+            !curve_type.uses_increment() || price_increment > 0,
+            BondingCurveError::ZeroIncrementNotAllowed
+        );
+        // Bounded supply keeps edition numbers (and the prices they drive) in range
+        require!(
+            max_supply > 0 && max_supply <= MAX_SUPPLY_CAP,
+            BondingCurveError::InvalidMaxSupply
+        );
+        require!(
+            !curve_type.needs_base_price() || base_price > 0,
+            BondingCurveError::InvalidBasePrice
+        );
+
         let curve = &mut ctx.accounts.bonding_curve;
         
         curve.authority = ctx.accounts.authority.key();
@@ -36,6 +76,21 @@ pub mod bonding_curve {
         // Set Bezier prices if provided
         curve.bezier_min_price = bezier_min_price.unwrap_or(base_price);
         curve.bezier_max_price = bezier_max_price.unwrap_or(base_price);
+        // Bezier pricing interpolates upward from min to max
+        require!(
+            curve_type != CurveType::Bezier || curve.bezier_min_price <= curve.bezier_max_price,
+            BondingCurveError::InvalidBezierRange
+        );
+        // Sigmoid pricing climbs from base_price up to its bezier_max_price ceiling
+        require!(
+            curve_type != CurveType::Sigmoid || curve.base_price <= curve.bezier_max_price,
+            BondingCurveError::InvalidSigmoidRange
+        );
+
+        // Price as if `supply_offset` editions were already sold
+        curve.supply_offset = supply_offset;
+
+        curve.created_at = Clock::get()?.unix_timestamp;
 
         msg!("Bonding curve initialized for collection: {}", curve.collection_mint);
         msg!("Type: {:?}, Base: {} lamports, Increment: {}", curve_type, base_price, price_increment);
@@ -43,241 +98,4579 @@ pub mod bonding_curve {
         if curve_type == CurveType::Bezier {
             msg!("Bezier range: {} to {} lamports", curve.bezier_min_price, curve.bezier_max_price);
         }
+        if curve_type == CurveType::Sigmoid {
+            msg!("Sigmoid range: {} to {} lamports", base_price, curve.bezier_max_price);
+        }
+        if curve_type == CurveType::DutchAuction {
+            msg!("Dutch auction from {} down to {} lamports", base_price, curve.bezier_min_price);
+        }
         
         Ok(())
     }
 
     /// Mint a new edition with bonding curve pricing
-    pub fn mint_edition(
-        ctx: Context<MintEdition>,
-    ) -> Result<()> {
+    /// Fails with `SlippageExceeded` if the price is above `max_price`
+    /// Token-priced curves (`payment_mint` set) take payment in that token into a curve-owned account
+    /// Returns the price charged and the new supply via return data
+    pub fn mint_edition<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintEdition<'info>>,
+        max_price: u64,
+    ) -> Result<MintOutcome> {
         let curve = &mut ctx.accounts.bonding_curve;
         
-        // Check if max supply reached
+        // Pause, supply, reservation and period-cap checks
+        check_mintable(curve, &ctx.accounts.next_reservation, &ctx.accounts.platform_config)?;
+        check_mint_cooldown(curve, &ctx.accounts.mint_counter)?;
+
+        // Calculate current price based on curve
+        let current_price = calculate_price(
+            curve,
+            curve.current_supply + 1, // Next edition number
+        )?;
+        // Promotional discount for this buyer, if it holds a coupon
+        let current_price = apply_coupon(&mut ctx.accounts.coupon, current_price)?;
+        // The USD floor is in lamports, so it only applies to native SOL pricing
+        let current_price = if curve.payment_mint.is_none() {
+            apply_usd_floor(curve, &ctx.accounts.price_feed, current_price)?
+        } else {
+            current_price
+        };
+
+        require!(current_price <= max_price, BondingCurveError::SlippageExceeded);
+
+        msg!("Minting edition #{} at {}", edition_number(curve, curve.current_supply + 1), current_price);
+
+        let net_proceeds = if let Some(payment_mint) = curve.payment_mint {
+            // SPL payment: platform fee off the top, then the rest into the vault's
+            // token account, to the creator's or across the royalty split (less any burn share)
+            let (Some(mint), Some(buyer_payment)) = (
+                &ctx.accounts.payment_mint,
+                &ctx.accounts.buyer_payment_account,
+            ) else {
+                return err!(BondingCurveError::PaymentAccountsRequired);
+            };
+            require_keys_eq!(mint.key(), payment_mint, BondingCurveError::PaymentMintMismatch);
+            require_keys_eq!(buyer_payment.mint, payment_mint, BondingCurveError::PaymentMintMismatch);
+            require_keys_eq!(buyer_payment.owner, ctx.accounts.buyer.key(), BondingCurveError::Unauthorized);
+            require!(
+                !curve.royalty_split_enabled || ctx.accounts.royalty_split.is_some(),
+                BondingCurveError::RoyaltySplitRequired
+            );
+
+            collect_token_payment(
+                curve,
+                &ctx.accounts.buyer.to_account_info(),
+                &mint.to_account_info(),
+                &buyer_payment.to_account_info(),
+                &ctx.accounts.authority_payment_account,
+                &ctx.accounts.treasury,
+                &ctx.accounts.treasury_payment_account,
+                &ctx.accounts.platform_config,
+                &ctx.accounts.platform_payment_account,
+                &ctx.accounts.royalty_split,
+                ctx.remaining_accounts,
+                &ctx.accounts.token_program.to_account_info(),
+                current_price,
+            )?
+        } else {
+            // Platform fee off the top, then the rest to creator(s) (less any burn share)
+            collect_payment(
+                curve,
+                &ctx.accounts.buyer.to_account_info(),
+                &ctx.accounts.authority_account.to_account_info(),
+                &ctx.accounts.treasury,
+                &ctx.accounts.incinerator,
+                &ctx.accounts.platform_config,
+                &ctx.accounts.platform_wallet,
+                &ctx.accounts.royalty_split,
+                ctx.remaining_accounts,
+                current_price,
+            )?
+        };
+
+        deliver_edition(
+            curve,
+            ctx.accounts.buyer.key(),
+            Delivery {
+                edition_mint: ctx.accounts.edition_mint.to_account_info(),
+                destination: ctx.accounts.buyer_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint_receipt: &mut ctx.accounts.mint_receipt,
+                receipt_bump: ctx.bumps.mint_receipt,
+                mint_counter: &mut ctx.accounts.mint_counter,
+                counter_bump: ctx.bumps.mint_counter,
+            },
+            current_price,
+            net_proceeds,
+        )?;
+
+        msg!("Edition #{} minted successfully!", edition_number(curve, curve.current_supply));
+        msg!("Total volume: {} lamports", curve.total_volume);
+
+        Ok(MintOutcome {
+            price: current_price,
+            new_supply: curve.current_supply,
+        })
+    }
+
+    /// Mint `quantity` consecutive editions in one transaction
+    /// Each edition is priced at its own supply; the buyer pays the sum in one
+    /// transfer. Fails with `SlippageExceeded` if the sum is above `max_total_price`
+    pub fn mint_edition_batch(
+        ctx: Context<MintEditionBatch>,
+        quantity: u8,
+        max_total_price: u64,
+    ) -> Result<()> {
+        let purchase = &mut ctx.accounts.purchase;
+        let bonding_curve_info = purchase.bonding_curve.to_account_info();
+        let curve = &mut purchase.bonding_curve;
+
+        require!(quantity > 0, BondingCurveError::InvalidQuantity);
+        // Several 1/1 editions cannot share one mint
         require!(
-            curve.current_supply < curve.max_supply,
+            quantity == 1 || curve.edition_decimals > 0,
+            BondingCurveError::InvalidEditionMint
+        );
+        require!(
+            curve.current_supply as u64 + quantity as u64 <= curve.max_supply as u64,
             BondingCurveError::MaxSupplyReached
         );
+        // Only the first edition's reservation PDA is passed in
+        require!(
+            quantity == 1 || curve.reserved_count == 0,
+            BondingCurveError::ReservationAccountRequired
+        );
+
+        // One cooldown check for the whole batch
+        check_mint_cooldown(curve, &purchase.mint_counter)?;
+
+        let mut total_price = 0u64;
+        let mut total_fee = 0u64;
+        let mut total_burn = 0u64;
+        for _ in 0..quantity {
+            // Pause, supply, reservation and period-cap checks
+            check_mintable(curve, &purchase.next_reservation, &purchase.platform_config)?;
+
+            let price = calculate_price(curve, curve.current_supply + 1)?;
+            let price = apply_usd_floor(curve, &purchase.price_feed, price)?;
+            total_price = total_price
+                .checked_add(price)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+            // Fee and burn are rounded per edition, exactly as for single mints
+            let split = split_payment(curve, &purchase.platform_config, price)?;
+            total_fee = total_fee
+                .checked_add(split.platform_fee)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?;
+            total_burn = total_burn
+                .checked_add(split.burn)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+            record_sale(curve, price)?;
+            emit_near_sellout(curve);
+
+            record_wallet_mint(
+                curve,
+                &mut purchase.mint_counter,
+                purchase.buyer.key(),
+                price,
+                ctx.bumps.purchase.mint_counter,
+            )?;
+            emit!(EditionMinted {
+                curve: curve.key(),
+                edition_number: edition_number(curve, curve.current_supply),
+                price,
+                buyer: purchase.buyer.key(),
+                new_supply: curve.current_supply,
+                unique_buyers: curve.unique_holders,
+            });
+        }
+
+        require!(total_price <= max_total_price, BondingCurveError::SlippageExceeded);
+
+        // Platform fee off the top, then a single transfer to creator for the
+        // whole batch (less the per-edition burn shares)
+        pay_platform_fee(
+            curve,
+            &purchase.buyer.to_account_info(),
+            &purchase.platform_config,
+            &purchase.platform_wallet,
+            &purchase.treasury,
+            total_fee,
+        )?;
+        let net_proceeds = route_payment(
+            curve,
+            &purchase.buyer.to_account_info(),
+            &purchase.authority_account.to_account_info(),
+            &purchase.treasury,
+            &purchase.incinerator,
+            &None,
+            &[],
+            total_price - total_fee,
+            total_burn,
+        )?;
+
+        // Tie the edition mint to this curve, recording what the whole batch paid
+        bind_mint_receipt(
+            &mut purchase.mint_receipt,
+            curve.key(),
+            purchase.edition_mint.key(),
+            purchase.buyer.key(),
+            edition_number(curve, curve.current_supply),
+            quantity as u32,
+            total_price,
+            net_proceeds,
+            ctx.bumps.purchase.mint_receipt,
+        )?;
+        curve.buyback_liability = curve.buyback_liability
+            .checked_add(net_proceeds)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+        mint_edition_token(
+            curve,
+            bonding_curve_info,
+            purchase.token_program.to_account_info(),
+            purchase.edition_mint.to_account_info(),
+            purchase.buyer_token_account.to_account_info(),
+            quantity as u32,
+        )?;
+
+        msg!("Batch of {} editions minted for {} lamports", quantity, total_price);
+        msg!("Total volume: {} lamports", curve.total_volume);
+
+        Ok(())
+    }
+
+    /// Mint an edition on behalf of a referrer, who receives `referral_bps` of the price
+    /// The rest is routed like any other mint; buyers cannot refer themselves
+    pub fn mint_edition_with_referral(
+        ctx: Context<MintEditionWithReferral>,
+        referrer: Pubkey,
+        max_price: u64,
+    ) -> Result<()> {
+        let purchase = &mut ctx.accounts.purchase;
+        let curve = &mut purchase.bonding_curve;
+
+        require_keys_neq!(referrer, purchase.buyer.key(), BondingCurveError::SelfReferral);
+
+        // Pause, supply, reservation and period-cap checks
+        check_mintable(curve, &purchase.next_reservation, &purchase.platform_config)?;
+        check_mint_cooldown(curve, &purchase.mint_counter)?;
 
-        // Calculate current price based on curve
         let current_price = calculate_price(
-            &curve.curve_type,
-            curve.base_price,
-            curve.price_increment,
+            curve,
             curve.current_supply + 1, // Next edition number
-            curve.bezier_min_price,
-            curve.bezier_max_price,
-            curve.max_supply,
         )?;
+        let current_price = apply_usd_floor(curve, &purchase.price_feed, current_price)?;
 
-        msg!("Minting edition #{} at {} lamports", curve.current_supply + 1, current_price);
+        // Referral reward off the top, rounded down in the creator's favor
+        let referral = referral_share(curve, current_price)?;
+        if referral > 0 {
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                &purchase.buyer.key(),
+                &referrer,
+                referral,
+            );
+            anchor_lang::solana_program::program::invoke(
+                &ix,
+                &[
+                    purchase.buyer.to_account_info(),
+                    ctx.accounts.referrer_account.to_account_info(),
+                ],
+            )?;
+        }
 
-        // Transfer payment from buyer to creator
-        let ix = anchor_lang::solana_program::system_instruction::transfer(
-            &ctx.accounts.buyer.key(),
-            &curve.authority,
+        // Remainder routed like any other mint
+        purchase.mint(&ctx.bumps.purchase, current_price, referral, max_price)?;
+
+        msg!("Edition #{} minted, {} lamports referral reward to {}", edition_number(&purchase.bonding_curve, purchase.bonding_curve.current_supply), referral, referrer);
+
+        Ok(())
+    }
+
+    /// Hold the next edition's current price for this buyer for `price_hold_secs`
+    /// Reserving again refreshes the hold at the then-current price
+    pub fn reserve_price(ctx: Context<ReservePrice>) -> Result<()> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        require!(curve.price_hold_secs > 0, BondingCurveError::PriceHoldDisabled);
+
+        let price = calculate_price(curve, curve.current_supply + 1)?;
+        let price = apply_usd_floor(curve, &ctx.accounts.price_feed, price)?;
+
+        let reservation = &mut ctx.accounts.price_reservation;
+        reservation.bonding_curve = curve.key();
+        reservation.buyer = ctx.accounts.buyer.key();
+        reservation.price = price;
+        reservation.expires_at = Clock::get()?.unix_timestamp
+            .checked_add(curve.price_hold_secs)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+        reservation.bump = ctx.bumps.price_reservation;
+
+        msg!("Price {} lamports held for {} until {}", price, reservation.buyer, reservation.expires_at);
+
+        Ok(())
+    }
+
+    /// Mint the next edition at the buyer's held price while the hold is unexpired
+    /// Charges the lower of the held and current price; the hold is consumed and any
+    /// shortfall below the curve price is recorded in `held_price_shortfall`
+    pub fn mint_edition_reserved(ctx: Context<MintEditionReserved>, max_price: u64) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        // Pause, supply, reservation and period-cap checks
+        check_mintable(curve, &ctx.accounts.next_reservation, &ctx.accounts.platform_config)?;
+        check_mint_cooldown(curve, &ctx.accounts.mint_counter)?;
+
+        let current_price = calculate_price(
+            curve,
+            curve.current_supply + 1, // Next edition number
+        )?;
+        let current_price = apply_usd_floor(curve, &ctx.accounts.price_feed, current_price)?;
+        let (charged, shortfall) = settle_price_hold(
+            &ctx.accounts.price_reservation,
             current_price,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        msg!("Held price {} lamports (curve price {})", charged, current_price);
+
+        do_mint(
+            curve,
+            &ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.buyer.key(),
+            Payees {
+                authority: &ctx.accounts.authority_account,
+                treasury: &ctx.accounts.treasury,
+                incinerator: &ctx.accounts.incinerator,
+                platform_config: &ctx.accounts.platform_config,
+                platform_wallet: &ctx.accounts.platform_wallet,
+            },
+            Delivery {
+                edition_mint: ctx.accounts.edition_mint.to_account_info(),
+                destination: ctx.accounts.buyer_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint_receipt: &mut ctx.accounts.mint_receipt,
+                receipt_bump: ctx.bumps.mint_receipt,
+                mint_counter: &mut ctx.accounts.mint_counter,
+                counter_bump: ctx.bumps.mint_counter,
+            },
+            charged,
+            0,
+            max_price,
+        )?;
+        curve.held_price_shortfall = curve.held_price_shortfall
+            .checked_add(shortfall)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+        msg!("Edition #{} minted at held price", edition_number(curve, curve.current_supply));
+
+        Ok(())
+    }
+
+    /// Mint an edition as a fresh Token-2022 mint carrying the transfer-hook extension
+    /// The hook program (which checks for a royalty payment) and the hook authority
+    /// are the curve's, so secondary transfers run through the creator's hook
+    pub fn mint_edition_hooked(
+        ctx: Context<MintEditionHooked>,
+        max_price: u64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            curve.transfer_hook_program != Pubkey::default(),
+            BondingCurveError::TransferHookNotConfigured
         );
-        anchor_lang::solana_program::program::invoke(
-            &ix,
-            &[
-                ctx.accounts.buyer.to_account_info(),
-                ctx.accounts.authority_account.to_account_info(),
-            ],
+
+        // Pause, supply, reservation and period-cap checks
+        check_mintable(curve, &ctx.accounts.next_reservation, &ctx.accounts.platform_config)?;
+        check_mint_cooldown(curve, &ctx.accounts.mint_counter)?;
+
+        let current_price = calculate_price(
+            curve,
+            curve.current_supply + 1, // Next edition number
         )?;
+        let current_price = apply_usd_floor(curve, &ctx.accounts.price_feed, current_price)?;
 
-        // Mint NFT token to buyer
-        let cpi_accounts = MintTo {
-            mint: ctx.accounts.edition_mint.to_account_info(),
-            to: ctx.accounts.buyer_token_account.to_account_info(),
-            authority: ctx.accounts.bonding_curve.to_account_info(),
-        };
-        
-        let seeds = &[
-            b"bonding_curve",
-            curve.collection_mint.as_ref(),
-            &[curve.bump],
-        ];
-        let signer = &[&seeds[..]];
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        
-        token::mint_to(cpi_ctx, 1)?;
+        do_mint(
+            curve,
+            &ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.buyer.key(),
+            Payees {
+                authority: &ctx.accounts.authority_account,
+                treasury: &ctx.accounts.treasury,
+                incinerator: &ctx.accounts.incinerator,
+                platform_config: &ctx.accounts.platform_config,
+                platform_wallet: &ctx.accounts.platform_wallet,
+            },
+            Delivery {
+                edition_mint: ctx.accounts.edition_mint.to_account_info(),
+                destination: ctx.accounts.buyer_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint_receipt: &mut ctx.accounts.mint_receipt,
+                receipt_bump: ctx.bumps.mint_receipt,
+                mint_counter: &mut ctx.accounts.mint_counter,
+                counter_bump: ctx.bumps.mint_counter,
+            },
+            current_price,
+            0,
+            max_price,
+        )?;
 
-        // Update curve state
-        curve.current_supply += 1;
-        curve.total_volume += current_price;
+        msg!("Edition #{} minted with transfer hook {}", edition_number(curve, curve.current_supply), curve.transfer_hook_program);
 
-        msg!("Edition #{} minted successfully!", curve.current_supply);
-        msg!("Total volume: {} lamports", curve.total_volume);
+        Ok(())
+    }
+
+    /// Mint triggered by the curve's operator for a co-signing buyer (relayed flows)
+    /// The operator pays account rent; the price still goes to the creator or treasury
+    pub fn mint_edition_operator(
+        ctx: Context<MintEditionOperator>,
+        max_price: u64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        // Compliance-gated curves only deliver through the KYC escrow
+        require!(
+            curve.compliance_authority == Pubkey::default(),
+            BondingCurveError::EscrowRequired
+        );
+        // Pause, supply, reservation and period-cap checks
+        check_mint_gates(curve, &ctx.accounts.next_reservation, &ctx.accounts.platform_config)?;
+        check_mint_cooldown(curve, &ctx.accounts.mint_counter)?;
+
+        let current_price = calculate_price(
+            curve,
+            curve.current_supply + 1, // Next edition number
+        )?;
+        let current_price = apply_usd_floor(curve, &ctx.accounts.price_feed, current_price)?;
+
+        // The buyer pays the price; the operator only authorizes and pays rent
+        do_mint(
+            curve,
+            &ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.buyer.key(),
+            Payees {
+                authority: &ctx.accounts.authority_account,
+                treasury: &ctx.accounts.treasury,
+                incinerator: &ctx.accounts.incinerator,
+                platform_config: &ctx.accounts.platform_config,
+                platform_wallet: &ctx.accounts.platform_wallet,
+            },
+            Delivery {
+                edition_mint: ctx.accounts.edition_mint.to_account_info(),
+                destination: ctx.accounts.buyer_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint_receipt: &mut ctx.accounts.mint_receipt,
+                receipt_bump: ctx.bumps.mint_receipt,
+                mint_counter: &mut ctx.accounts.mint_counter,
+                counter_bump: ctx.bumps.mint_counter,
+            },
+            current_price,
+            0,
+            max_price,
+        )?;
+
+        msg!("Edition #{} minted by operator {}", edition_number(curve, curve.current_supply), ctx.accounts.operator.key());
 
         Ok(())
     }
 
     /// Update bonding curve parameters (authority only)
+    /// `curve_type` is fixed at init: passing a different `new_curve_type` fails
+    /// With governance configured, raising `max_supply` needs a covering `GovernanceApproval`
     pub fn update_curve(
         ctx: Context<UpdateCurve>,
         new_base_price: Option<u64>,
         new_price_increment: Option<u64>,
         new_max_supply: Option<u32>,
+        new_curve_type: Option<CurveType>,
     ) -> Result<()> {
         let curve = &mut ctx.accounts.bonding_curve;
+
+        // Switching type mid-drop would reprice every remaining edition
+        if let Some(curve_type) = new_curve_type {
+            require!(
+                curve_type == curve.curve_type,
+                BondingCurveError::CurveTypeImmutable
+            );
+        }
         
         if let Some(base_price) = new_base_price {
+            require!(
+                !curve.curve_type.needs_base_price() || base_price > 0,
+                BondingCurveError::InvalidBasePrice
+            );
+            require!(
+                curve.curve_type != CurveType::Sigmoid || base_price <= curve.bezier_max_price,
+                BondingCurveError::InvalidSigmoidRange
+            );
             curve.base_price = base_price;
             msg!("Updated base price to: {} lamports", base_price);
         }
         
         if let Some(increment) = new_price_increment {
+            require!(
+                !curve.curve_type.uses_increment() || increment > 0,
+                BondingCurveError::ZeroIncrementNotAllowed
+            );
             curve.price_increment = increment;
             msg!("Updated price increment to: {} lamports", increment);
         }
         
         if let Some(max_supply) = new_max_supply {
             require!(
-                max_supply >= curve.current_supply,
+                max_supply >= curve.current_supply && max_supply > 0 && max_supply <= MAX_SUPPLY_CAP,
+                BondingCurveError::InvalidMaxSupply
+            );
+            // Outstanding reservations must still fit under the new cap
+            let reserved_floor = curve.current_supply
+                .checked_add(curve.reserved_count)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?;
+            require!(
+                max_supply >= reserved_floor
+                    && (curve.reserved_count == 0 || max_supply >= curve.highest_reserved),
+                BondingCurveError::MaxSupplyBelowReserved
+            );
+            // Supply increases are gated behind governance when configured
+            if max_supply > curve.max_supply && curve.governance != Pubkey::default() {
+                let approval = ctx.accounts.governance_approval
+                    .as_ref()
+                    .ok_or(BondingCurveError::GovernanceApprovalRequired)?;
+                require!(
+                    max_supply <= approval.approved_max_supply,
+                    BondingCurveError::GovernanceApprovalRequired
+                );
+            }
+            // Reverse serials are derived from max_supply, so it is fixed once minting starts
+            require!(
+                !curve.reverse_numbering || curve.current_supply == 0,
                 BondingCurveError::InvalidMaxSupply
             );
             curve.max_supply = max_supply;
             msg!("Updated max supply to: {}", max_supply);
         }
 
+        check_absolute_max_price(curve)?;
+
+        emit!(CurveUpdated {
+            curve: curve.key(),
+            base_price: curve.base_price,
+            price_increment: curve.price_increment,
+            max_supply: curve.max_supply,
+        });
+
         Ok(())
     }
 
-    /// Close the bonding curve and reclaim rent (authority only, must be empty)
-    pub fn close_curve(ctx: Context<CloseCurve>) -> Result<()> {
-        let curve = &ctx.accounts.bonding_curve;
-        
+    /// Steepen a Linear curve after a milestone (authority only)
+    /// Once `steepen_at` editions are sold the increment becomes `post_steepen_increment`; 0 disables
+    pub fn set_steepening(
+        ctx: Context<UpdateCurve>,
+        steepen_at: u32,
+        post_steepen_increment: u64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
         require!(
-            curve.current_supply == 0,
-            BondingCurveError::CurveNotEmpty
+            curve.curve_type == CurveType::Linear,
+            BondingCurveError::InvalidCurveType
+        );
+        require!(
+            steepen_at <= curve.max_supply,
+            BondingCurveError::InvalidMaxSupply
         );
 
-        msg!("Closing bonding curve for collection: {}", curve.collection_mint);
-        
+        curve.steepen_at = steepen_at;
+        curve.post_steepen_increment = post_steepen_increment;
+        check_absolute_max_price(curve)?;
+
+        msg!("Curve steepens to {} lamports/edition after {} editions", post_steepen_increment, steepen_at);
+
         Ok(())
     }
 
-    /// Initialize a Bezier price lookup table for complex curves
-    /// Stores pre-calculated prices for each edition to avoid on-chain computation
-    pub fn initialize_bezier_lookup(
-        ctx: Context<InitializeBezierLookup>,
-        prices: Vec<u64>, // Pre-calculated price for each edition
+    /// Cap the price of the curve's final edition (authority only)
+    /// Rejected if the current configuration already exceeds it; `None` removes the cap
+    pub fn set_absolute_max_price(
+        ctx: Context<UpdateCurve>,
+        absolute_max_price: Option<u64>,
     ) -> Result<()> {
-        let lookup = &mut ctx.accounts.bezier_lookup;
-        
-        require!(
-            prices.len() as u32 <= ctx.accounts.bonding_curve.max_supply,
-            BondingCurveError::InvalidPriceLookup
-        );
-        
-        lookup.bonding_curve = ctx.accounts.bonding_curve.key();
-        lookup.prices = prices;
-        lookup.bump = ctx.bumps.bezier_lookup;
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.absolute_max_price = absolute_max_price.unwrap_or(0);
+        check_absolute_max_price(curve)?;
+
+        msg!("Absolute max price set to {} lamports", curve.absolute_max_price);
 
-        msg!("Bezier price lookup initialized with {} entries", lookup.prices.len());
-        
         Ok(())
     }
 
-    /// Mint edition using Bezier lookup table
-    pub fn mint_edition_with_bezier_lookup(
-        ctx: Context<MintEditionWithBezierLookup>,
+    /// Set the share of each payment burned to the incinerator (authority only)
+    pub fn set_burn_bps(
+        ctx: Context<UpdateCurve>,
+        burn_bps: u16,
     ) -> Result<()> {
-        let curve = &mut ctx.accounts.bonding_curve;
-        let lookup = &ctx.accounts.bezier_lookup;
-        
-        // Check if max supply reached
+        require!(burn_bps <= 10000, BondingCurveError::InvalidFeeBps);
+        // Crowdfund and refund-window refunds return the price, so nothing may be burned
         require!(
-            curve.current_supply < curve.max_supply,
-            BondingCurveError::MaxSupplyReached
+            burn_bps == 0
+                || (ctx.accounts.bonding_curve.min_goal == 0 && ctx.accounts.bonding_curve.refund_window == 0),
+            BondingCurveError::InvalidFeeBps
         );
 
-        // Get pre-calculated price from lookup table
-        let edition_idx = curve.current_supply as usize;
-        let current_price = lookup.prices.get(edition_idx)
-            .ok_or(BondingCurveError::PriceNotFound)?;
+        ctx.accounts.bonding_curve.burn_bps = burn_bps;
 
-        msg!("Minting edition #{} at {} lamports (from lookup)", curve.current_supply + 1, current_price);
+        msg!("Burn fee set to {} bps", burn_bps);
 
-        // Transfer payment from buyer to creator
-        let ix = anchor_lang::solana_program::system_instruction::transfer(
-            &ctx.accounts.buyer.key(),
-            &curve.authority,
-            *current_price,
-        );
-        anchor_lang::solana_program::program::invoke(
-            &ix,
-            &[
-                ctx.accounts.buyer.to_account_info(),
-                ctx.accounts.authority_account.to_account_info(),
-            ],
-        )?;
+        Ok(())
+    }
 
-        // Mint NFT token to buyer
-        let cpi_accounts = MintTo {
-            mint: ctx.accounts.edition_mint.to_account_info(),
-            to: ctx.accounts.buyer_token_account.to_account_info(),
-            authority: ctx.accounts.bonding_curve.to_account_info(),
-        };
-        
-        let seeds = &[
-            b"bonding_curve",
-            curve.collection_mint.as_ref(),
-            &[curve.bump],
-        ];
-        let signer = &[&seeds[..]];
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        
-        token::mint_to(cpi_ctx, 1)?;
+    /// Set the share of each referred mint's price paid to the referrer (authority only)
+    /// Not allowed on crowdfunds, whose refunds return the full price
+    pub fn set_referral_bps(ctx: Context<UpdateCurve>, referral_bps: u16) -> Result<()> {
+        require!(referral_bps <= 10000, BondingCurveError::InvalidFeeBps);
+        require!(
+            referral_bps == 0 || ctx.accounts.bonding_curve.min_goal == 0,
+            BondingCurveError::InvalidFeeBps
+        );
 
-        // Update curve state
-        curve.current_supply += 1;
-        curve.total_volume += current_price;
+        ctx.accounts.bonding_curve.referral_bps = referral_bps;
 
-        msg!("Edition #{} minted successfully with Bezier lookup!", curve.current_supply);
-        msg!("Total volume: {} lamports", curve.total_volume);
+        msg!("Referral reward set to {} bps", referral_bps);
 
         Ok(())
     }
-}
 
-// Calculate price based on curve type and edition number
-fn calculate_price(
-    curve_type: &CurveType,
-    base_price: u64,
-    price_increment: u64,
-    edition: u32,
-    bezier_min_price: u64,
-    bezier_max_price: u64,
-    max_supply: u32,
-) -> Result<u64> {
-    let price = match curve_type {
-        CurveType::Linear => {
-            // price = base_price + (edition - 1) * increment
-            base_price
-                .checked_add((edition as u64 - 1).checked_mul(price_increment).unwrap())
-                .unwrap()
+    /// Pause or resume minting, recording why (authority only)
+    pub fn set_paused(
+        ctx: Context<UpdateCurve>,
+        paused: bool,
+        reason: PauseReason,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.paused = paused;
+        curve.pause_reason = reason.clone();
+
+        if paused {
+            msg!("Minting paused: {:?}", reason);
+            emit!(Paused { curve: curve.key(), reason });
+        } else {
+            msg!("Minting resumed: {:?}", reason);
+            emit!(Unpaused { curve: curve.key(), reason });
+        }
+
+        Ok(())
+    }
+
+    /// Cap mints per time period, e.g. 100 per day (authority only)
+    /// `period_secs = 0` disables the throttle
+    pub fn set_period_cap(
+        ctx: Context<UpdateCurve>,
+        period_secs: i64,
+        period_cap: u32,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(period_secs >= 0, BondingCurveError::InvalidPeriod);
+
+        curve.period_secs = period_secs;
+        curve.period_cap = period_cap;
+        curve.period_start = Clock::get()?.unix_timestamp;
+        curve.period_minted = 0;
+
+        msg!("Mint cap set to {} per {} seconds", period_cap, period_secs);
+
+        Ok(())
+    }
+
+    /// Configure a USD floor enforced via a price feed (authority only)
+    /// Pass `oracle = None` to disable; `usd_floor` is in micro-USD
+    pub fn set_usd_floor(
+        ctx: Context<UpdateCurve>,
+        oracle: Option<Pubkey>,
+        usd_floor: u64,
+        max_oracle_staleness: i64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(max_oracle_staleness >= 0, BondingCurveError::OracleStale);
+
+        curve.oracle = oracle.unwrap_or_default();
+        curve.usd_floor = usd_floor;
+        curve.max_oracle_staleness = max_oracle_staleness;
+
+        msg!("USD floor set to {} micro-USD (oracle: {})", usd_floor, curve.oracle);
+
+        Ok(())
+    }
+
+    /// Set the Merkle root of `(wallet, max_qty)` allowlist leaves (authority only)
+    /// Pass `None` to close the allowlist
+    pub fn set_merkle_root(
+        ctx: Context<UpdateCurve>,
+        merkle_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+        curve.merkle_root = merkle_root;
+
+        msg!("Allowlist {} for collection: {}", if merkle_root.is_some() { "set" } else { "cleared" }, curve.collection_mint);
+
+        Ok(())
+    }
+
+    /// Hand max_supply increases to a governance address (authority only)
+    /// One-way: once set, only governance can approve raising the supply cap
+    pub fn set_governance(ctx: Context<UpdateCurve>, governance: Pubkey) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            curve.governance == Pubkey::default(),
+            BondingCurveError::GovernanceAlreadySet
+        );
+        require!(
+            governance != Pubkey::default(),
+            BondingCurveError::Unauthorized
+        );
+        curve.governance = governance;
+
+        msg!("Supply increases now gated by governance: {}", governance);
+
+        Ok(())
+    }
+
+    /// Approve raising max_supply up to `approved_max_supply` (governance only)
+    /// Re-approving overwrites the ceiling, so governance can also revoke
+    pub fn approve_supply_increase(
+        ctx: Context<ApproveSupplyIncrease>,
+        approved_max_supply: u32,
+    ) -> Result<()> {
+        let approval = &mut ctx.accounts.governance_approval;
+        approval.bonding_curve = ctx.accounts.bonding_curve.key();
+        approval.approved_max_supply = approved_max_supply;
+        approval.bump = ctx.bumps.governance_approval;
+
+        msg!("Governance approved max supply up to {}", approved_max_supply);
+
+        Ok(())
+    }
+
+    /// Set the trusted reporter of secondary-market volume (authority only)
+    /// Pass `None` to stop accepting reports
+    pub fn set_volume_reporter(
+        ctx: Context<UpdateCurve>,
+        volume_reporter: Option<Pubkey>,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+        curve.volume_reporter = volume_reporter.unwrap_or_default();
+
+        msg!("Volume reporter set to: {}", curve.volume_reporter);
+
+        Ok(())
+    }
+
+    /// Log secondary-market volume reported by the configured reporter
+    /// Tracked separately from primary `total_volume`
+    pub fn record_secondary_volume(
+        ctx: Context<RecordSecondaryVolume>,
+        amount: u64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.secondary_volume = curve.secondary_volume
+            .checked_add(amount)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+        emit!(SecondaryVolumeRecorded {
+            curve: curve.key(),
+            amount,
+            secondary_volume: curve.secondary_volume,
+        });
+
+        msg!("Secondary volume +{} lamports (total {})", amount, curve.secondary_volume);
+
+        Ok(())
+    }
+
+    /// Add `time_premium_per_day` lamports per full day since creation to every price (authority only)
+    /// 0 disables the premium
+    pub fn set_time_premium(
+        ctx: Context<UpdateCurve>,
+        time_premium_per_day: u64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+        curve.time_premium_per_day = time_premium_per_day;
+
+        msg!("Time premium set to {} lamports per day", time_premium_per_day);
+
+        Ok(())
+    }
+
+    /// Create a push price feed reporting micro-USD per SOL
+    pub fn initialize_price_feed(
+        ctx: Context<InitializePriceFeed>,
+        usd_per_sol: u64,
+    ) -> Result<()> {
+        let feed = &mut ctx.accounts.price_feed;
+
+        feed.authority = ctx.accounts.authority.key();
+        feed.usd_per_sol = usd_per_sol;
+        feed.updated_at = Clock::get()?.unix_timestamp;
+        feed.bump = ctx.bumps.price_feed;
+
+        msg!("Price feed initialized at {} micro-USD/SOL", usd_per_sol);
+
+        Ok(())
+    }
+
+    /// Publish a new price to a feed (feed authority only)
+    pub fn update_price_feed(
+        ctx: Context<UpdatePriceFeed>,
+        usd_per_sol: u64,
+    ) -> Result<()> {
+        let feed = &mut ctx.accounts.price_feed;
+
+        feed.usd_per_sol = usd_per_sol;
+        feed.updated_at = Clock::get()?.unix_timestamp;
+
+        msg!("Price feed updated to {} micro-USD/SOL", usd_per_sol);
+
+        Ok(())
+    }
+
+    /// Create the program-wide platform fee config; the signer becomes its admin
+    pub fn initialize_platform_config(
+        ctx: Context<InitializePlatformConfig>,
+        platform_fee_bps: u16,
+        platform_wallet: Pubkey,
+    ) -> Result<()> {
+        require!(platform_fee_bps <= 10000, BondingCurveError::InvalidFeeBps);
+
+        let config = &mut ctx.accounts.platform_config;
+        config.admin = ctx.accounts.admin.key();
+        config.platform_fee_bps = platform_fee_bps;
+        config.platform_wallet = platform_wallet;
+        config.bump = ctx.bumps.platform_config;
+
+        msg!("Platform fee set to {} bps, paid to {}", platform_fee_bps, platform_wallet);
+
+        Ok(())
+    }
+
+    /// Change the platform fee or the wallet it is paid to (platform admin only)
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        platform_fee_bps: u16,
+        platform_wallet: Pubkey,
+    ) -> Result<()> {
+        require!(platform_fee_bps <= 10000, BondingCurveError::InvalidFeeBps);
+
+        let config = &mut ctx.accounts.platform_config;
+        config.platform_fee_bps = platform_fee_bps;
+        config.platform_wallet = platform_wallet;
+
+        msg!("Platform fee updated to {} bps, paid to {}", platform_fee_bps, platform_wallet);
+
+        Ok(())
+    }
+
+    /// Pause or resume minting on every curve at once (platform admin only)
+    pub fn set_global_pause(ctx: Context<UpdatePlatformConfig>, paused: bool) -> Result<()> {
+        ctx.accounts.platform_config.paused_globally = paused;
+
+        msg!("Minting {} platform-wide", if paused { "paused" } else { "resumed" });
+
+        Ok(())
+    }
+
+    /// Split creator proceeds among up to 5 recipients by basis points (authority only)
+    /// mint_edition then pays each recipient, passed as remaining accounts in split order
+    pub fn initialize_royalty_split(
+        ctx: Context<InitializeRoyaltySplit>,
+        recipients: Vec<RoyaltyRecipient>,
+    ) -> Result<()> {
+        require!(
+            !recipients.is_empty() && recipients.len() <= MAX_ROYALTY_RECIPIENTS,
+            BondingCurveError::InvalidRoyaltySplit
+        );
+        let total_bps = recipients
+            .iter()
+            .try_fold(0u16, |sum, r| sum.checked_add(r.bps))
+            .ok_or(BondingCurveError::InvalidRoyaltySplit)?;
+        require!(total_bps == 10000, BondingCurveError::InvalidRoyaltySplit);
+
+        let split = &mut ctx.accounts.royalty_split;
+        split.bonding_curve = ctx.accounts.bonding_curve.key();
+        split.recipients = recipients;
+        split.bump = ctx.bumps.royalty_split;
+
+        ctx.accounts.bonding_curve.royalty_split_enabled = true;
+
+        msg!("Royalty split set across {} recipients", split.recipients.len());
+
+        Ok(())
+    }
+
+    /// Grant `buyer` a `discount_bps` discount on its next `uses` mints via mint_edition
+    /// (authority only); re-issuing replaces the buyer's existing coupon
+    pub fn issue_coupon(
+        ctx: Context<IssueCoupon>,
+        buyer: Pubkey,
+        discount_bps: u16,
+        uses: u8,
+    ) -> Result<()> {
+        require!(discount_bps <= 10000, BondingCurveError::InvalidDiscountBps);
+
+        let coupon = &mut ctx.accounts.coupon;
+        coupon.bonding_curve = ctx.accounts.bonding_curve.key();
+        coupon.buyer = buyer;
+        coupon.discount_bps = discount_bps;
+        coupon.uses_remaining = uses;
+        coupon.bump = ctx.bumps.coupon;
+
+        msg!("Coupon issued to {}: {} bps off, {} uses", buyer, discount_bps, uses);
+
+        Ok(())
+    }
+
+    /// Create or update the authority's creator profile: default fee settings shared
+    /// by every curve linked to it (applied via link/sync_creator_profile)
+    pub fn upsert_creator_profile(
+        ctx: Context<UpsertCreatorProfile>,
+        burn_bps: u16,
+        referral_bps: u16,
+    ) -> Result<()> {
+        require!(burn_bps <= 10000, BondingCurveError::InvalidFeeBps);
+        require!(referral_bps <= 10000, BondingCurveError::InvalidFeeBps);
+
+        let profile = &mut ctx.accounts.creator_profile;
+        profile.authority = ctx.accounts.authority.key();
+        profile.burn_bps = burn_bps;
+        profile.referral_bps = referral_bps;
+        profile.bump = ctx.bumps.creator_profile;
+
+        msg!("Creator profile {}: burn {} bps, referral {} bps", profile.authority, burn_bps, referral_bps);
+
+        Ok(())
+    }
+
+    /// Link a curve to its authority's creator profile and apply the profile's settings
+    /// (authority only); later profile changes reach the curve via sync_creator_profile
+    pub fn link_creator_profile(ctx: Context<LinkCreatorProfile>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.creator_profile = ctx.accounts.creator_profile.key();
+        apply_creator_profile(curve, &ctx.accounts.creator_profile)?;
+
+        msg!("Curve linked to creator profile {}", curve.creator_profile);
+
+        Ok(())
+    }
+
+    /// Re-apply a linked curve's creator profile after it changed (callable by anyone)
+    pub fn sync_creator_profile(ctx: Context<SyncCreatorProfile>) -> Result<()> {
+        apply_creator_profile(&mut ctx.accounts.bonding_curve, &ctx.accounts.creator_profile)?;
+
+        msg!("Creator profile synced to curve {}", ctx.accounts.bonding_curve.key());
+
+        Ok(())
+    }
+
+    /// Number editions from max_supply downwards (authority only, before the first mint)
+    /// Pricing still follows the curve on current_supply; only serials change
+    pub fn set_reverse_numbering(
+        ctx: Context<UpdateCurve>,
+        reverse_numbering: bool,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+        curve.reverse_numbering = reverse_numbering;
+
+        msg!("Reverse numbering: {}", reverse_numbering);
+
+        Ok(())
+    }
+
+    /// Blind drop: freeze minted editions until reveal (authority only, before the first mint)
+    /// The edition mints' freeze authority must be the curve PDA
+    pub fn set_blind_drop(
+        ctx: Context<UpdateCurve>,
+        frozen_until_reveal: bool,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+        curve.frozen_until_reveal = frozen_until_reveal;
+
+        msg!("Blind drop: {}", frozen_until_reveal);
+
+        Ok(())
+    }
+
+    /// Opt in to royalty-enforcing editions: `mint_edition_hooked` then creates each
+    /// edition as a Token-2022 mint whose transfer hook is `transfer_hook_program`
+    /// (authority only, before the first mint; default disables)
+    pub fn set_transfer_hook_program(
+        ctx: Context<UpdateCurve>,
+        transfer_hook_program: Pubkey,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+        curve.transfer_hook_program = transfer_hook_program;
+
+        msg!("Transfer hook program set to {}", transfer_hook_program);
+
+        Ok(())
+    }
+
+    /// Soulbound mode: freeze each minted edition until `unfreeze_editions` (authority only)
+    /// The edition mints' freeze authority must be the curve PDA
+    pub fn set_freeze_on_mint(ctx: Context<UpdateCurve>, freeze_on_mint: bool) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.freeze_on_mint = freeze_on_mint;
+
+        msg!("Freeze on mint: {}", freeze_on_mint);
+
+        Ok(())
+    }
+
+    /// Lift soulbound mode (authority only); editions frozen on mint can then be thawed
+    pub fn unfreeze_editions(ctx: Context<UpdateCurve>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.freeze_on_mint, BondingCurveError::EditionsNotLocked);
+        curve.freeze_on_mint = false;
+
+        msg!("Editions unfrozen for collection: {}", curve.collection_mint);
+
+        Ok(())
+    }
+
+    /// Reveal a blind drop (authority only); frozen editions can then be thawed
+    /// Metadata URIs are not stored by this program, so updating them happens off-chain
+    pub fn reveal(ctx: Context<UpdateCurve>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(!curve.revealed, BondingCurveError::AlreadyRevealed);
+        curve.revealed = true;
+
+        msg!("Collection revealed: {}", curve.collection_mint);
+
+        Ok(())
+    }
+
+    /// Thaw an edition frozen before reveal or on mint (callable by anyone once
+    /// the blind drop is revealed and soulbound mode is lifted)
+    pub fn thaw_edition(ctx: Context<ThawEdition>) -> Result<()> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        require!(!curve.frozen_until_reveal || curve.revealed, BondingCurveError::NotRevealed);
+        require!(!curve.freeze_on_mint, BondingCurveError::EditionsLocked);
+
+        let seeds = &[
+            b"bonding_curve",
+            curve.collection_mint.as_ref(),
+            &[curve.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = ThawAccount {
+            account: ctx.accounts.token_account.to_account_info(),
+            mint: ctx.accounts.edition_mint.to_account_info(),
+            authority: ctx.accounts.bonding_curve.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+        token_interface::thaw_account(cpi_ctx)?;
+
+        msg!("Edition {} thawed", ctx.accounts.edition_mint.key());
+
+        Ok(())
+    }
+
+    /// Halt or resume sell-backs without touching minting (authority only)
+    pub fn set_sellback_paused(ctx: Context<UpdateCurve>, sellback_paused: bool) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+        curve.sellback_paused = sellback_paused;
+
+        msg!("Sell-backs {} for collection: {}", if sellback_paused { "paused" } else { "resumed" }, curve.collection_mint);
+
+        Ok(())
+    }
+
+    /// Emit a one-time NearSellout event once this share of supply is minted (authority only)
+    /// In basis points; 0 disables
+    pub fn set_near_sellout_bps(ctx: Context<UpdateCurve>, near_sellout_bps: u16) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(near_sellout_bps <= 10000, BondingCurveError::InvalidThresholdBps);
+        curve.near_sellout_bps = near_sellout_bps;
+
+        msg!("Near-sellout threshold set to {} bps", near_sellout_bps);
+
+        Ok(())
+    }
+
+    /// Expected edition mint decimals (authority only, before the first mint)
+    /// 0 (default) is pure NFT mode; up to 9 for semi-fungible editions
+    pub fn set_edition_decimals(ctx: Context<UpdateCurve>, edition_decimals: u8) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+        require!(edition_decimals <= 9, BondingCurveError::InvalidMintDecimals);
+        curve.edition_decimals = edition_decimals;
+
+        msg!("Edition decimals set to {}", edition_decimals);
+
+        Ok(())
+    }
+
+    /// Cap the number of distinct wallets that can mint (authority only)
+    /// Existing minters can keep minting past the cap; 0 means unlimited
+    pub fn set_max_unique_minters(ctx: Context<UpdateCurve>, max_unique_minters: u32) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+        curve.max_unique_minters = max_unique_minters;
+
+        msg!("Max unique minters set to {} ({} so far)", max_unique_minters, curve.unique_holders);
+
+        Ok(())
+    }
+
+    /// Price the curve in an SPL token (authority only, before the first mint)
+    /// `None` returns to native SOL pricing; `flat_fee` lamports are charged on top by mint_edition_dual_pay
+    pub fn set_dual_payment(
+        ctx: Context<UpdateCurve>,
+        payment_mint: Option<Pubkey>,
+        flat_fee: u64,
+        fee_recipient: Pubkey,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+        curve.payment_mint = payment_mint;
+        curve.flat_fee = flat_fee;
+        curve.fee_recipient = fee_recipient;
+
+        msg!("Payment mint: {:?}, flat fee {} lamports to {}", payment_mint, flat_fee, fee_recipient);
+
+        Ok(())
+    }
+
+    /// Require KYC: mints go to escrow until `compliance_authority` releases them
+    /// (authority only, before the first mint); `None` delivers directly again
+    pub fn set_compliance_authority(
+        ctx: Context<UpdateCurve>,
+        compliance_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+        curve.compliance_authority = compliance_authority.unwrap_or_default();
+
+        msg!("Compliance authority set to: {}", curve.compliance_authority);
+
+        Ok(())
+    }
+
+    /// All-or-nothing crowdfund: if `min_goal` lamports are not raised by `deadline`,
+    /// holders can refund (authority only, before the first mint; `min_goal = 0` disables)
+    /// Proceeds are held in the treasury vault, and burn fees and referral rewards are not allowed
+    pub fn set_crowdfund(ctx: Context<UpdateCurve>, min_goal: u64, deadline: i64) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+        if min_goal > 0 {
+            require!(curve.burn_bps == 0, BondingCurveError::InvalidFeeBps);
+            require!(curve.referral_bps == 0, BondingCurveError::InvalidFeeBps);
+            require!(
+                deadline > Clock::get()?.unix_timestamp,
+                BondingCurveError::InvalidTargetTime
+            );
+        }
+
+        curve.min_goal = min_goal;
+        curve.deadline = deadline;
+
+        msg!("Crowdfund goal {} lamports by {}", min_goal, deadline);
+
+        Ok(())
+    }
+
+    /// Hold mint proceeds in the treasury vault PDA instead of paying the creator
+    /// (authority only, before the first mint); the vault then backs sell-backs
+    pub fn set_treasury_enabled(ctx: Context<UpdateCurve>, treasury_enabled: bool) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+
+        let (_, treasury_bump) = Pubkey::find_program_address(
+            &[b"treasury", curve.key().as_ref()],
+            ctx.program_id,
+        );
+        curve.treasury_enabled = treasury_enabled;
+        curve.treasury_bump = treasury_bump;
+        if treasury_enabled && !curve.sellback_disabled {
+            curve.sellback_enabled = true;
+        }
+
+        msg!("Treasury vault {} for collection: {}", if treasury_enabled { "enabled" } else { "disabled" }, curve.collection_mint);
+
+        Ok(())
+    }
+
+    /// Propose a new authority (authority only); takes effect once the proposed
+    /// key signs `accept_authority`, so a mistyped address cannot lock the curve
+    pub fn propose_authority(ctx: Context<UpdateCurve>, new_authority: Pubkey) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.pending_authority = Some(new_authority);
+
+        msg!("Authority handoff proposed: {} -> {}", curve.authority, new_authority);
+
+        Ok(())
+    }
+
+    /// Finalize a proposed authority handoff (pending authority only)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.authority = ctx.accounts.new_authority.key();
+        curve.pending_authority = None;
+
+        msg!("Authority transferred to: {}", curve.authority);
+
+        Ok(())
+    }
+
+    /// Keep `reserve_ratio` bps of the buy-back liability in the treasury vault
+    /// (authority only); 10000 fully backs every outstanding edition
+    pub fn set_reserve_ratio(ctx: Context<UpdateCurve>, reserve_ratio: u16) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(reserve_ratio <= 10000, BondingCurveError::InvalidThresholdBps);
+        require!(
+            reserve_ratio == 0 || curve.proceeds_to_treasury(),
+            BondingCurveError::TreasuryRequired
+        );
+        curve.reserve_ratio = reserve_ratio;
+
+        msg!("Reserve ratio set to {} bps", reserve_ratio);
+
+        Ok(())
+    }
+
+    /// Let buyers return an edition for its price within `refund_window` seconds
+    /// of minting (authority only, 0 disables); refunds are paid from the treasury vault
+    /// Not allowed alongside a burn share, which never reaches the vault
+    pub fn set_refund_window(ctx: Context<UpdateCurve>, refund_window: u32) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            refund_window == 0 || curve.proceeds_to_treasury(),
+            BondingCurveError::TreasuryRequired
+        );
+        require!(
+            refund_window == 0 || curve.burn_bps == 0,
+            BondingCurveError::InvalidFeeBps
+        );
+        curve.refund_window = refund_window as i64;
+
+        msg!("Refund window set to {} seconds", refund_window);
+
+        Ok(())
+    }
+
+    /// How long `reserve_price` holds a price for a buyer (authority only, 0 disables)
+    pub fn set_price_hold_secs(ctx: Context<UpdateCurve>, price_hold_secs: u32) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.price_hold_secs = price_hold_secs as i64;
+
+        msg!("Price hold set to {} seconds", price_hold_secs);
+
+        Ok(())
+    }
+
+    /// Delegate mint triggering to an operator hot wallet (authority only)
+    /// The operator gets no payout or admin rights; with `operator_only` set, every
+    /// direct mint path is closed and editions go out via mint_edition_operator
+    pub fn set_operator(
+        ctx: Context<UpdateCurve>,
+        operator: Pubkey,
+        operator_only: bool,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            !operator_only || operator != Pubkey::default(),
+            BondingCurveError::OperatorRequired
+        );
+        curve.operator = operator;
+        curve.operator_only = operator_only;
+
+        msg!("Operator set to {} (operator only: {})", operator, operator_only);
+
+        Ok(())
+    }
+
+    /// Graduate to a flat price: once `flat_after` editions are sold, every further
+    /// edition costs what the next one did at that point (authority only, 0 disables)
+    pub fn set_flat_after(ctx: Context<UpdateCurve>, flat_after: u32) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.flat_after = flat_after;
+        check_absolute_max_price(curve)?;
+
+        msg!("Price flattens after {} editions", flat_after);
+
+        Ok(())
+    }
+
+    /// Cap on team editions taken from the public supply via mint_team_allocation
+    /// (authority only, before the first mint; 0 allows none)
+    pub fn set_max_team_allocation(ctx: Context<UpdateCurve>, max_team_allocation: u32) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+        require!(max_team_allocation <= curve.max_supply, BondingCurveError::TeamAllocationExceeded);
+        curve.max_team_allocation = max_team_allocation;
+
+        msg!("Team allocation capped at {} editions", max_team_allocation);
+
+        Ok(())
+    }
+
+    /// Cap on free editions minted outside the public numbering via mint_reserve
+    /// (authority only; 0 allows none, and it cannot drop below what is already minted)
+    pub fn set_max_reserve_mints(ctx: Context<UpdateCurve>, max_reserve_mints: u32) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            max_reserve_mints >= curve.reserved_minted,
+            BondingCurveError::ReserveAllocationExceeded
+        );
+        curve.max_reserve_mints = max_reserve_mints;
+
+        msg!("Reserve mints capped at {} editions", max_reserve_mints);
+
+        Ok(())
+    }
+
+    /// Minimum seconds between mints from the same wallet (authority only, 0 disables)
+    pub fn set_mint_cooldown(ctx: Context<UpdateCurve>, mint_cooldown_secs: u32) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.mint_cooldown_secs = mint_cooldown_secs;
+
+        msg!("Mint cooldown set to {} seconds", mint_cooldown_secs);
+
+        Ok(())
+    }
+
+    /// Editions per price tier for Step curves (authority only, before the first mint)
+    pub fn set_tier_size(ctx: Context<UpdateCurve>, tier_size: u32) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            curve.curve_type == CurveType::Step,
+            BondingCurveError::InvalidCurveType
+        );
+        require!(tier_size > 0, BondingCurveError::InvalidTierSize);
+        require!(curve.current_supply == 0, BondingCurveError::CurveNotEmpty);
+        curve.tier_size = tier_size;
+
+        msg!("Tier size set to {} editions", tier_size);
+
+        Ok(())
+    }
+
+    /// Restrict minting to a time window (authority only)
+    /// `start_ts = 0` opens immediately; `end_ts = 0` means no end
+    pub fn set_mint_window(ctx: Context<UpdateCurve>, start_ts: i64, end_ts: i64) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            end_ts == 0 || end_ts > start_ts,
+            BondingCurveError::InvalidMintWindow
+        );
+        curve.start_ts = start_ts;
+        curve.end_ts = end_ts;
+
+        msg!("Mint window: {} to {}", start_ts, end_ts);
+
+        Ok(())
+    }
+
+    /// Permanently disable sell-backs into the curve (authority only, one-way)
+    pub fn disable_sellback(ctx: Context<UpdateCurve>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(!curve.sellback_disabled, BondingCurveError::SellbackDisabled);
+        curve.sellback_disabled = true;
+
+        msg!("Sell-backs permanently disabled for collection: {}", curve.collection_mint);
+
+        Ok(())
+    }
+
+    /// Mint a team edition at no cost (authority only)
+    /// Counted in `reserved_minted`, not `current_supply`, so public pricing is unaffected;
+    /// capped by `max_reserve_mints`
+    pub fn mint_reserve(
+        ctx: Context<MintReserve>,
+    ) -> Result<()> {
+        let bonding_curve_info = ctx.accounts.bonding_curve.to_account_info();
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        record_reserve_mint(curve)?;
+
+        // Team editions sit outside the public numbering (edition 0)
+        bind_mint_receipt(
+            &mut ctx.accounts.mint_receipt,
+            curve.key(),
+            ctx.accounts.edition_mint.key(),
+            ctx.accounts.recipient.key(),
+            0,
+            1,
+            0,
+            0,
+            ctx.bumps.mint_receipt,
+        )?;
+
+        mint_edition_token(
+            curve,
+            bonding_curve_info,
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.edition_mint.to_account_info(),
+            ctx.accounts.recipient_token_account.to_account_info(),
+            1,
+        )?;
+
+        msg!("Reserve edition minted to {} ({} of {} reserve mints)", ctx.accounts.recipient.key(), curve.reserved_minted, curve.max_reserve_mints);
+
+        Ok(())
+    }
+
+    /// Mint `quantity` team editions to `recipient` at no cost (authority only)
+    /// Unlike mint_reserve these take public editions: `current_supply` advances so
+    /// the public price reflects them, but `total_volume` does not; capped by `max_team_allocation`
+    pub fn mint_team_allocation(
+        ctx: Context<MintTeamAllocation>,
+        quantity: u32,
+    ) -> Result<()> {
+        let bonding_curve_info = ctx.accounts.bonding_curve.to_account_info();
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(quantity > 0, BondingCurveError::InvalidQuantity);
+        // Several 1/1 editions cannot share one mint
+        require!(
+            quantity == 1 || curve.edition_decimals > 0,
+            BondingCurveError::InvalidEditionMint
+        );
+        require!(
+            curve.team_allocation_minted as u64 + quantity as u64 <= curve.max_team_allocation as u64,
+            BondingCurveError::TeamAllocationExceeded
+        );
+        require!(
+            curve.current_supply as u64 + quantity as u64 <= curve.max_supply as u64,
+            BondingCurveError::MaxSupplyReached
+        );
+        // Editions held via reserve_specific must not be taken
+        require!(curve.reserved_count == 0, BondingCurveError::ReservationAccountRequired);
+
+        // Tie the edition mint to this curve at the last team edition
+        bind_mint_receipt(
+            &mut ctx.accounts.mint_receipt,
+            curve.key(),
+            ctx.accounts.edition_mint.key(),
+            ctx.accounts.recipient.key(),
+            edition_number(curve, curve.current_supply + quantity),
+            quantity,
+            0,
+            0,
+            ctx.bumps.mint_receipt,
+        )?;
+
+        mint_edition_token(
+            curve,
+            bonding_curve_info,
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.edition_mint.to_account_info(),
+            ctx.accounts.recipient_token_account.to_account_info(),
+            quantity,
+        )?;
+
+        for _ in 0..quantity {
+            curve.current_supply += 1;
+            emit_near_sellout(curve);
+
+            emit!(EditionMinted {
+                curve: curve.key(),
+                edition_number: edition_number(curve, curve.current_supply),
+                price: 0,
+                buyer: ctx.accounts.recipient.key(),
+                new_supply: curve.current_supply,
+                unique_buyers: curve.unique_holders,
+            });
+        }
+        curve.team_allocation_minted += quantity;
+
+        msg!("{} team editions minted to {} ({} of {} allocated)", quantity, ctx.accounts.recipient.key(), curve.team_allocation_minted, curve.max_team_allocation);
+
+        Ok(())
+    }
+
+    /// Reserve a specific edition number, paying its curve price now
+    /// The edition is delivered via claim_reserved once the sale reaches it; the
+    /// payment is held in the treasury vault until then so cancel_reservation can return it
+    pub fn reserve_specific(
+        ctx: Context<ReserveSpecific>,
+        edition: u32,
+        max_price: u64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        // Same gates as a public mint, and the payment must stay refundable
+        check_reservable(curve, &ctx.accounts.platform_config)?;
+        require!(curve.proceeds_to_treasury(), BondingCurveError::TreasuryRequired);
+        require!(
+            edition > curve.current_supply && edition <= curve.max_supply,
+            BondingCurveError::InvalidEdition
+        );
+
+        let price = calculate_price(curve, edition)?;
+        let price = apply_usd_floor(curve, &ctx.accounts.price_feed, price)?;
+
+        require!(price <= max_price, BondingCurveError::SlippageExceeded);
+
+        let net_proceeds = collect_payment(
+            curve,
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.authority_account.to_account_info(),
+            &ctx.accounts.treasury,
+            &ctx.accounts.incinerator,
+            &ctx.accounts.platform_config,
+            &ctx.accounts.platform_wallet,
+            &None,
+            &[],
+            price,
+        )?;
+
+        let reservation = &mut ctx.accounts.reservation;
+        reservation.bonding_curve = curve.key();
+        reservation.buyer = ctx.accounts.buyer.key();
+        reservation.edition = edition;
+        reservation.price_paid = price;
+        reservation.bump = ctx.bumps.reservation;
+        reservation.net_proceeds = net_proceeds;
+        reservation.expires_at = if curve.reservation_secs > 0 {
+            Clock::get()?.unix_timestamp
+                .checked_add(curve.reservation_secs)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+        } else {
+            0
+        };
+
+        curve.reserved_count = curve.reserved_count
+            .checked_add(1)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+        curve.reserved_proceeds = curve.reserved_proceeds
+            .checked_add(net_proceeds)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+        curve.highest_reserved = curve.highest_reserved.max(edition);
+
+        msg!("Edition #{} reserved for {} at {} lamports", edition, reservation.buyer, price);
+
+        Ok(())
+    }
+
+    /// Deliver a reserved edition once it is next in line (callable by anyone)
+    pub fn claim_reserved(
+        ctx: Context<ClaimReserved>,
+    ) -> Result<()> {
+        let bonding_curve_info = ctx.accounts.bonding_curve.to_account_info();
+        let curve = &mut ctx.accounts.bonding_curve;
+        let reservation = &ctx.accounts.reservation;
+
+        ensure_not_globally_paused(&ctx.accounts.platform_config)?;
+        require!(!curve.paused, BondingCurveError::CurvePaused);
+        require!(
+            curve.compliance_authority == Pubkey::default(),
+            BondingCurveError::EscrowRequired
+        );
+        require!(
+            reservation.edition == curve.current_supply + 1,
+            BondingCurveError::ReservationNotReady
+        );
+
+        // Tie the edition mint to this curve
+        bind_mint_receipt(
+            &mut ctx.accounts.mint_receipt,
+            curve.key(),
+            ctx.accounts.edition_mint.key(),
+            reservation.buyer,
+            edition_number(curve, reservation.edition),
+            1,
+            reservation.price_paid,
+            reservation.net_proceeds,
+            ctx.bumps.mint_receipt,
+        )?;
+
+        // Mint NFT token to the reservation holder (already paid)
+        mint_edition_token(
+            curve,
+            bonding_curve_info,
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.edition_mint.to_account_info(),
+            ctx.accounts.buyer_token_account.to_account_info(),
+            1,
+        )?;
+
+        record_sale(curve, reservation.price_paid)?;
+        curve.buyback_liability = curve.buyback_liability
+            .checked_add(reservation.net_proceeds)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+        curve.reserved_count -= 1;
+        curve.reserved_proceeds = curve.reserved_proceeds.saturating_sub(reservation.net_proceeds);
+        emit_near_sellout(curve);
+
+        record_wallet_mint(
+            curve,
+            &mut ctx.accounts.mint_counter,
+            reservation.buyer,
+            reservation.price_paid,
+            ctx.bumps.mint_counter,
+        )?;
+        emit!(EditionMinted {
+            curve: curve.key(),
+            edition_number: edition_number(curve, curve.current_supply),
+            price: reservation.price_paid,
+            buyer: reservation.buyer,
+            new_supply: curve.current_supply,
+            unique_buyers: curve.unique_holders,
+        });
+
+        msg!("Reserved edition #{} claimed by {}", edition_number(curve, reservation.edition), reservation.buyer);
+
+        Ok(())
+    }
+
+    /// Give up an unclaimed reservation and take back what the vault kept of its price
+    /// Only once the reservation has expired or the sale has closed without reaching it
+    pub fn cancel_reservation(ctx: Context<CancelReservation>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+        let reservation = &ctx.accounts.reservation;
+
+        require!(
+            reservation_cancellable(curve, reservation, Clock::get()?.unix_timestamp),
+            BondingCurveError::ReservationActive
+        );
+
+        let refund = reservation.net_proceeds;
+        curve.reserved_count -= 1;
+        curve.reserved_proceeds = curve.reserved_proceeds.saturating_sub(refund);
+
+        pay_from_treasury(
+            curve,
+            &ctx.accounts.treasury.to_account_info(),
+            ctx.bumps.treasury,
+            &ctx.accounts.buyer.to_account_info(),
+            &None,
+            &None,
+            None,
+            refund,
+        )?;
+
+        msg!("Reservation of edition #{} cancelled, {} lamports returned", edition_number(curve, reservation.edition), refund);
+
+        Ok(())
+    }
+
+    /// How long a reserve_specific reservation stays binding before its buyer
+    /// may cancel it (authority only, 0 = until the sale closes)
+    pub fn set_reservation_secs(ctx: Context<UpdateCurve>, reservation_secs: u32) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.reservation_secs = reservation_secs as i64;
+
+        msg!("Reservations expire after {} seconds", reservation_secs);
+
+        Ok(())
+    }
+
+    /// Close the bonding curve and reclaim rent (authority only, must be empty)
+    pub fn close_curve(ctx: Context<CloseCurve>) -> Result<()> {
+        let curve = &ctx.accounts.bonding_curve;
+        
+        require!(
+            curve.current_supply == 0,
+            BondingCurveError::CurveNotEmpty
+        );
+
+        msg!("Closing bonding curve for collection: {}", curve.collection_mint);
+
+        emit!(CurveClosed {
+            curve: curve.key(),
+            collection_mint: curve.collection_mint,
+        });
+        
+        Ok(())
+    }
+
+    /// Seed the treasury vault from the authority and enable sell-backs
+    /// For curves whose past proceeds went straight to the authority
+    pub fn backfill_vault(ctx: Context<BackfillVault>, amount: u64) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(!curve.sellback_disabled, BondingCurveError::SellbackDisabled);
+
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.treasury.key(),
+            amount,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[
+                ctx.accounts.authority.to_account_info(),
+                ctx.accounts.treasury.to_account_info(),
+            ],
+        )?;
+
+        curve.sellback_enabled = true;
+        curve.backfilled_reserve = curve.backfilled_reserve
+            .checked_add(amount)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+        msg!("Backfilled vault with {} lamports ({} total)", amount, curve.backfilled_reserve);
+
+        Ok(())
+    }
+
+    /// Withdraw `amount` from the treasury vault (authority only), in lamports or,
+    /// on a token-priced curve, in the payment token from the vault's token account
+    /// The vault must keep enough to buy back every outstanding edition while sell-backs are possible,
+    /// plus (for lamports) the platform fees accrued in it and its rent-exempt minimum
+    pub fn withdraw_treasury(ctx: Context<SweepVault>, amount: u64) -> Result<()> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        require!(!curve.crowdfund_locked(), BondingCurveError::FundsLocked);
+
+        let reserve = if let Some(payment_mint) = curve.payment_mint {
+            // Platform fees on token payments are never held by the vault
+            let reserve = required_reserve(curve)?;
+            let vault = vault_payment_account(
+                ctx.accounts.treasury.key(),
+                payment_mint,
+                &ctx.accounts.treasury_payment_account,
+            )?;
+            let left = vault.amount
+                .checked_sub(amount)
+                .ok_or(BondingCurveError::InsufficientReserve)?;
+            require!(left >= reserve, BondingCurveError::InsufficientReserve);
+            reserve
+        } else {
+            let reserve = required_reserve(curve)?
+                .checked_add(curve.accrued_platform_fees)
+                .and_then(|reserve| reserve.checked_add(curve.reserved_proceeds))
+                .ok_or(BondingCurveError::ArithmeticOverflow)?;
+            let balance = ctx.accounts.treasury.lamports();
+            let left = balance
+                .checked_sub(amount)
+                .ok_or(BondingCurveError::InsufficientReserve)?;
+            require!(left >= reserve, BondingCurveError::InsufficientReserve);
+
+            // The vault must stay rent-exempt on top of its liabilities; only
+            // sweep_and_close_vault may drain it
+            let rent_floor = Rent::get()?.minimum_balance(ctx.accounts.treasury.data_len());
+            require!(
+                left - reserve >= rent_floor,
+                BondingCurveError::InsufficientTreasuryBalance
+            );
+            reserve
+        };
+
+        pay_from_treasury(
+            curve,
+            &ctx.accounts.treasury.to_account_info(),
+            ctx.bumps.treasury,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.treasury_payment_account,
+            &ctx.accounts.authority_payment_account,
+            ctx.accounts.token_program.as_ref().map(|program| program.to_account_info()),
+            amount,
+        )?;
+
+        msg!("Withdrew {} from vault ({} reserved for buy-backs)", amount, reserve);
+
+        Ok(())
+    }
+
+    /// Pay the platform fees accrued in a curve's treasury vault to the platform wallet
+    /// (platform admin only); the curve authority cannot withdraw this portion
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        let amount = curve.accrued_platform_fees;
+        require!(amount > 0, BondingCurveError::NoFeesAccrued);
+        // Vault funds stay put while buyers may still be owed refunds
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !curve.crowdfund_locked() && !curve.refunding(now) && !curve.refund_window_open(now),
+            BondingCurveError::FundsLocked
+        );
+
+        let curve_key = curve.key();
+        let seeds = &[
+            b"treasury",
+            curve_key.as_ref(),
+            &[ctx.bumps.treasury],
+        ];
+        let signer = &[&seeds[..]];
+
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.treasury.key(),
+            &ctx.accounts.platform_wallet.key(),
+            amount,
+        );
+        anchor_lang::solana_program::program::invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.platform_wallet.to_account_info(),
+            ],
+            signer,
+        )?;
+
+        curve.accrued_platform_fees = 0;
+
+        msg!("Withdrew {} lamports of platform fees from vault", amount);
+
+        Ok(())
+    }
+
+    /// Sweep the treasury vault to the authority and close it at wind-down
+    /// Only once sell-backs are disabled or no editions are outstanding, and no
+    /// refunds can still be claimed
+    pub fn sweep_and_close_vault(ctx: Context<SweepVault>) -> Result<()> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        ensure_vault_sweepable(curve)?;
+
+        let amount = ctx.accounts.treasury.lamports();
+        let curve_key = curve.key();
+        let seeds = &[
+            b"treasury",
+            curve_key.as_ref(),
+            &[ctx.bumps.treasury],
+        ];
+        let signer = &[&seeds[..]];
+
+        // Draining a system-owned PDA to zero closes it
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.treasury.key(),
+            &ctx.accounts.authority.key(),
+            amount,
+        );
+        anchor_lang::solana_program::program::invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.authority.to_account_info(),
+            ],
+            signer,
+        )?;
+
+        msg!("Swept {} lamports from vault and closed it", amount);
+
+        Ok(())
+    }
+
+    /// Wind down a drop in one call: sweep the vault, close the Bezier lookup (if any)
+    /// and the curve, returning everything to the authority
+    /// Requires everything sweep_and_close_vault does, plus no outstanding reservations
+    /// and no editions still in KYC escrow (releasing them needs the curve)
+    pub fn teardown(ctx: Context<Teardown>) -> Result<()> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        ensure_vault_sweepable(curve)?;
+        require!(curve.reserved_count == 0, BondingCurveError::CurveNotEmpty);
+        require!(curve.pending_escrows == 0, BondingCurveError::CurveNotEmpty);
+
+        let amount = ctx.accounts.treasury.lamports();
+        if amount > 0 {
+            let curve_key = curve.key();
+            let seeds = &[
+                b"treasury",
+                curve_key.as_ref(),
+                &[ctx.bumps.treasury],
+            ];
+            let signer = &[&seeds[..]];
+
+            // Draining a system-owned PDA to zero closes it
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.treasury.key(),
+                &ctx.accounts.authority.key(),
+                amount,
+            );
+            anchor_lang::solana_program::program::invoke_signed(
+                &ix,
+                &[
+                    ctx.accounts.treasury.to_account_info(),
+                    ctx.accounts.authority.to_account_info(),
+                ],
+                signer,
+            )?;
+        }
+
+        msg!("Teardown of collection {}: swept {} lamports from vault", curve.collection_mint, amount);
+
+        Ok(())
+    }
+
+    /// Sell an edition back into the curve: burns it and refunds the current top price
+    /// (the price of edition `current_supply`) from the treasury vault, capped at
+    /// what the vault kept from the original sale; free editions cannot be sold
+    pub fn sell_edition(ctx: Context<SellEdition>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(!curve.sellback_disabled, BondingCurveError::SellbackDisabled);
+        require!(!curve.sellback_paused, BondingCurveError::SellbackPaused);
+        require!(curve.sellback_enabled, BondingCurveError::SellbackNotEnabled);
+        require!(!curve.crowdfund_locked(), BondingCurveError::FundsLocked);
+        // Fractional holders share one receipt, so its proceeds can't be attributed
+        require!(curve.edition_decimals == 0, BondingCurveError::SharedReceipt);
+        // Free team and reserve editions were never paid into the vault
+        let receipt = &ctx.accounts.mint_receipt;
+        require!(
+            receipt.price_paid > 0 && receipt.edition > 0,
+            BondingCurveError::UnpaidEdition
+        );
+
+        // A batch receipt returns every edition it covers
+        let editions = receipt.editions;
+        require!(
+            editions > 0 && curve.current_supply >= editions,
+            BondingCurveError::InvalidEdition
+        );
+
+        let refund = sellback_refund(curve, receipt)?;
+        // Accrued platform fees belong to the platform, not to sellers
+        require!(
+            curve.payment_mint.is_some()
+                || ctx.accounts.treasury.lamports().saturating_sub(curve.accrued_platform_fees) >= refund,
+            BondingCurveError::InsufficientTreasuryBalance
+        );
+
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.edition_mint.to_account_info(),
+            from: ctx.accounts.seller_token_account.to_account_info(),
+            authority: ctx.accounts.seller.to_account_info(),
+        };
+        token::burn(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            editions as u64 * 10u64.pow(curve.edition_decimals as u32),
+        )?;
+
+        pay_from_treasury(
+            curve,
+            &ctx.accounts.treasury.to_account_info(),
+            ctx.bumps.treasury,
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.treasury_payment_account,
+            &ctx.accounts.seller_payment_account,
+            Some(ctx.accounts.token_program.to_account_info()),
+            refund,
+        )?;
+
+        let sold_edition = edition_number(curve, curve.current_supply);
+        settle_sellback(curve, &ctx.accounts.mint_receipt, refund)?;
+
+        emit!(EditionSold {
+            curve: curve.key(),
+            edition_number: sold_edition,
+            price: refund,
+            seller: ctx.accounts.seller.key(),
+            new_supply: curve.current_supply,
+        });
+
+        msg!("Edition {} sold back for {}", ctx.accounts.edition_mint.key(), refund);
+
+        Ok(())
+    }
+
+    /// A holder burns their edition and reclaims what the vault kept of its price:
+    /// within `refund_window` seconds of the mint, or for any edition once a
+    /// crowdfunded drop misses `min_goal` by its deadline (all-or-nothing refund)
+    /// A failed crowdfund also returns the platform fee accrued for the edition;
+    /// refunds are paid in the curve's payment token when it has one
+    pub fn refund_edition(ctx: Context<RefundEdition>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+        let now = Clock::get()?.unix_timestamp;
+
+        check_refundable(curve, &ctx.accounts.mint_receipt, ctx.accounts.holder.key(), now)?;
+
+        let editions = ctx.accounts.mint_receipt.editions;
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.edition_mint.to_account_info(),
+            from: ctx.accounts.holder_token_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        };
+        token::burn(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            editions as u64 * 10u64.pow(curve.edition_decimals as u32),
+        )?;
+
+        let refund = settle_refund(curve, &ctx.accounts.mint_receipt, now)?;
+        pay_from_treasury(
+            curve,
+            &ctx.accounts.treasury.to_account_info(),
+            ctx.bumps.treasury,
+            &ctx.accounts.holder.to_account_info(),
+            &ctx.accounts.treasury_payment_account,
+            &ctx.accounts.holder_payment_account,
+            Some(ctx.accounts.token_program.to_account_info()),
+            refund,
+        )?;
+
+        msg!("Refunded {} for edition {}", refund, ctx.accounts.edition_mint.key());
+
+        Ok(())
+    }
+
+    /// Initialize a Bezier price lookup table for complex curves
+    /// Stores pre-calculated prices for each edition to avoid on-chain computation
+    pub fn initialize_bezier_lookup(
+        ctx: Context<InitializeBezierLookup>,
+        prices: Vec<u64>, // Pre-calculated price for each edition
+    ) -> Result<()> {
+        let lookup = &mut ctx.accounts.bezier_lookup;
+        
+        require!(
+            prices.len() as u32 <= ctx.accounts.bonding_curve.max_supply,
+            BondingCurveError::InvalidPriceLookup
+        );
+        // A curve prices from either the single lookup or chunks, never both
+        require!(
+            !ctx.accounts.bonding_curve.bezier_chunked,
+            BondingCurveError::BezierLookupConflict
+        );
+        
+        lookup.bonding_curve = ctx.accounts.bonding_curve.key();
+        lookup.prices = prices;
+        lookup.bump = ctx.bumps.bezier_lookup;
+
+        msg!("Bezier price lookup initialized with {} entries", lookup.prices.len());
+        
+        Ok(())
+    }
+
+    /// Initialize one chunk of a paginated Bezier lookup (authority only)
+    /// Chunk `i` holds the prices for editions `i * BEZIER_CHUNK_SIZE + 1` onwards
+    pub fn initialize_bezier_chunk(
+        ctx: Context<InitializeBezierChunk>,
+        chunk_index: u32,
+        prices: Vec<u64>,
+    ) -> Result<()> {
+        let chunk = &mut ctx.accounts.bezier_chunk;
+
+        let first_index = chunk_index
+            .checked_mul(BEZIER_CHUNK_SIZE)
+            .ok_or(BondingCurveError::InvalidPriceLookup)?;
+        require!(
+            prices.len() as u32 <= BEZIER_CHUNK_SIZE
+                && first_index as u64 + prices.len() as u64 <= ctx.accounts.bonding_curve.max_supply as u64,
+            BondingCurveError::InvalidPriceLookup
+        );
+        require!(
+            ctx.accounts.bezier_lookup.data_is_empty(),
+            BondingCurveError::BezierLookupConflict
+        );
+        ctx.accounts.bonding_curve.bezier_chunked = true;
+
+        chunk.bonding_curve = ctx.accounts.bonding_curve.key();
+        chunk.chunk_index = chunk_index;
+        chunk.prices = prices;
+        chunk.bump = ctx.bumps.bezier_chunk;
+
+        msg!("Bezier lookup chunk {} initialized with {} entries", chunk_index, chunk.prices.len());
+
+        Ok(())
+    }
+
+    /// Switch a live curve to a Bezier lookup for its remaining editions (authority only)
+    /// `prices` covers editions `current_supply + 1..=max_supply`; minted editions keep
+    /// their original curve prices in the lookup
+    pub fn migrate_to_bezier(ctx: Context<MigrateToBezier>, prices: Vec<u64>) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            curve.curve_type != CurveType::Bezier,
+            BondingCurveError::InvalidCurveType
+        );
+        // The single lookup account holds at most 1000 prices
+        require!(
+            prices.len() as u64 == (curve.max_supply - curve.current_supply) as u64
+                && curve.max_supply <= 1000,
+            BondingCurveError::InvalidPriceLookup
+        );
+
+        let mut schedule = Vec::with_capacity(curve.max_supply as usize);
+        for edition in 1..=curve.current_supply {
+            schedule.push(calculate_price(curve, edition)?);
+        }
+        schedule.extend_from_slice(&prices);
+
+        // Interpolated Bezier quotes stay within the new schedule's range
+        curve.bezier_min_price = prices.iter().copied().min().unwrap_or(curve.base_price);
+        curve.bezier_max_price = prices.iter().copied().max().unwrap_or(curve.base_price);
+        curve.curve_type = CurveType::Bezier;
+
+        let lookup = &mut ctx.accounts.bezier_lookup;
+        lookup.bonding_curve = curve.key();
+        lookup.prices = schedule;
+        lookup.bump = ctx.bumps.bezier_lookup;
+
+        msg!("Migrated to Bezier lookup at supply {} with {} new prices", curve.current_supply, prices.len());
+
+        Ok(())
+    }
+
+    /// Overwrite lookup prices starting at `start_index` (authority only)
+    /// Only entries for editions not yet minted may change
+    pub fn update_bezier_lookup(
+        ctx: Context<UpdateBezierLookup>,
+        start_index: u32,
+        prices: Vec<u64>,
+    ) -> Result<()> {
+        let lookup = &mut ctx.accounts.bezier_lookup;
+
+        require!(
+            start_index >= ctx.accounts.bonding_curve.current_supply,
+            BondingCurveError::LookupEntryMinted
+        );
+        let start = start_index as usize;
+        let end = start
+            .checked_add(prices.len())
+            .ok_or(BondingCurveError::InvalidPriceLookup)?;
+        require!(end <= lookup.prices.len(), BondingCurveError::InvalidPriceLookup);
+
+        lookup.prices[start..end].copy_from_slice(&prices);
+
+        msg!("Bezier price lookup updated: entries {} to {}", start, end);
+
+        Ok(())
+    }
+
+    /// Mint edition using Bezier lookup table
+    /// Fails with `SlippageExceeded` if the price is above `max_price`
+    pub fn mint_edition_with_bezier_lookup(
+        ctx: Context<MintEditionWithBezierLookup>,
+        max_price: u64,
+    ) -> Result<()> {
+        let purchase = &mut ctx.accounts.purchase;
+        let curve = &mut purchase.bonding_curve;
+        
+        // Pause, supply, reservation and period-cap checks
+        check_mintable(curve, &purchase.next_reservation, &purchase.platform_config)?;
+        check_mint_cooldown(curve, &purchase.mint_counter)?;
+
+        // Get pre-calculated price from the lookup chunk covering this edition
+        // on chunked curves, or from the single lookup table otherwise
+        let edition_idx = curve.current_supply;
+        let lookup_price = if curve.bezier_chunked {
+            let (chunk_index, offset) = bezier_chunk_slot(edition_idx);
+            ctx.accounts.bezier_chunk.as_ref()
+                .filter(|chunk| chunk.chunk_index == chunk_index)
+                .and_then(|chunk| chunk.prices.get(offset))
+        } else {
+            ctx.accounts.bezier_lookup.as_ref()
+                .and_then(|lookup| lookup.prices.get(edition_idx as usize))
+        };
+        let current_price = lookup_price
+            .ok_or(BondingCurveError::PriceNotFound)?
+            .checked_add(time_premium(curve)?)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+        let current_price = apply_usd_floor(curve, &purchase.price_feed, current_price)?;
+
+        purchase.mint(&ctx.bumps.purchase, current_price, 0, max_price)?;
+
+        msg!("Edition #{} minted successfully with Bezier lookup!", edition_number(&purchase.bonding_curve, purchase.bonding_curve.current_supply));
+        msg!("Total volume: {} lamports", purchase.bonding_curve.total_volume);
+
+        Ok(())
+    }
+
+    /// Set aside lamports that relayers draw on to pay for this buyer's permit mints
+    pub fn fund_permit_deposit(ctx: Context<FundPermitDeposit>, amount: u64) -> Result<()> {
+        let deposit = &mut ctx.accounts.permit_deposit;
+        deposit.bonding_curve = ctx.accounts.bonding_curve.key();
+        deposit.buyer = ctx.accounts.buyer.key();
+        deposit.bump = ctx.bumps.permit_deposit;
+
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.buyer.key(),
+            &deposit.key(),
+            amount,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[
+                ctx.accounts.buyer.to_account_info(),
+                deposit.to_account_info(),
+            ],
+        )?;
+
+        msg!("Permit deposit funded with {} lamports", amount);
+
+        Ok(())
+    }
+
+    /// Take back unspent lamports from a permit deposit (buyer only); the account
+    /// keeps its rent-exempt minimum
+    pub fn withdraw_permit_deposit(ctx: Context<WithdrawPermitDeposit>, amount: u64) -> Result<()> {
+        let deposit = ctx.accounts.permit_deposit.to_account_info();
+        require!(
+            permit_deposit_balance(&deposit)? >= amount,
+            BondingCurveError::InsufficientDeposit
+        );
+
+        deposit.sub_lamports(amount)?;
+        ctx.accounts.buyer.add_lamports(amount)?;
+
+        msg!("Withdrew {} lamports from permit deposit", amount);
+
+        Ok(())
+    }
+
+    /// Mint on behalf of a buyer who signed an off-chain permit (gasless relay)
+    /// The preceding instruction must be an ed25519 verification of the permit message,
+    /// which binds the curve, edition mint and relayer; the price comes out of the
+    /// buyer's permit deposit, the relayer pays only rent, the edition goes to the buyer
+    pub fn mint_with_permit(
+        ctx: Context<MintWithPermit>,
+        max_price: u64,
+        expires_at: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            Clock::get()?.unix_timestamp <= expires_at,
+            BondingCurveError::PermitExpired
+        );
+
+        let message = permit_message(
+            &curve.key(),
+            &ctx.accounts.edition_mint.key(),
+            &ctx.accounts.buyer.key(),
+            &ctx.accounts.relayer.key(),
+            max_price,
+            expires_at,
+            nonce,
+        );
+        verify_ed25519_permit(
+            &ctx.accounts.instructions_sysvar,
+            &ctx.accounts.buyer.key(),
+            &message,
+        )?;
+
+        // Pause, supply, reservation and period-cap checks
+        check_mintable(curve, &ctx.accounts.next_reservation, &ctx.accounts.platform_config)?;
+        check_mint_cooldown(curve, &ctx.accounts.mint_counter)?;
+
+        let current_price = calculate_price(
+            curve,
+            curve.current_supply + 1, // Next edition number
+        )?;
+        let current_price = apply_usd_floor(curve, &ctx.accounts.price_feed, current_price)?;
+
+        let permit = &mut ctx.accounts.permit;
+        permit.buyer = ctx.accounts.buyer.key();
+        permit.nonce = nonce;
+        permit.bump = ctx.bumps.permit;
+
+        // The buyer funds the price; the relayer only forwards it
+        let deposit = ctx.accounts.permit_deposit.to_account_info();
+        require!(
+            permit_deposit_balance(&deposit)? >= current_price,
+            BondingCurveError::InsufficientDeposit
+        );
+        deposit.sub_lamports(current_price)?;
+        ctx.accounts.relayer.add_lamports(current_price)?;
+
+        do_mint(
+            curve,
+            &ctx.accounts.relayer.to_account_info(),
+            ctx.accounts.buyer.key(),
+            Payees {
+                authority: &ctx.accounts.authority_account,
+                treasury: &ctx.accounts.treasury,
+                incinerator: &ctx.accounts.incinerator,
+                platform_config: &ctx.accounts.platform_config,
+                platform_wallet: &ctx.accounts.platform_wallet,
+            },
+            Delivery {
+                edition_mint: ctx.accounts.edition_mint.to_account_info(),
+                destination: ctx.accounts.buyer_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint_receipt: &mut ctx.accounts.mint_receipt,
+                receipt_bump: ctx.bumps.mint_receipt,
+                mint_counter: &mut ctx.accounts.mint_counter,
+                counter_bump: ctx.bumps.mint_counter,
+            },
+            current_price,
+            0,
+            max_price,
+        )?;
+
+        msg!("Edition #{} minted to {} via permit", edition_number(curve, curve.current_supply), ctx.accounts.buyer.key());
+
+        Ok(())
+    }
+
+    /// Mint for an allowlisted wallet; the Merkle leaf commits `(buyer, max_qty)`
+    /// and the wallet's total mints (per its MintCounter) are capped at `max_qty`
+    pub fn mint_edition_allowlisted(
+        ctx: Context<MintEditionAllowlisted>,
+        max_qty: u32,
+        proof: Vec<[u8; 32]>,
+        max_price: u64,
+    ) -> Result<()> {
+        let leaf = allowlist_leaf(&ctx.accounts.purchase.buyer.key(), max_qty);
+        mint_from_allowlist(ctx, leaf, &proof, Some(max_qty), max_price)
+    }
+
+    /// Presale mint for a whitelisted wallet; the Merkle leaf commits only the buyer
+    /// Shares `merkle_root` with the allowlist path but puts no cap on quantity
+    pub fn mint_edition_whitelisted(
+        ctx: Context<MintEditionAllowlisted>,
+        proof: Vec<[u8; 32]>,
+        max_price: u64,
+    ) -> Result<()> {
+        let leaf = whitelist_leaf(&ctx.accounts.purchase.buyer.key());
+        mint_from_allowlist(ctx, leaf, &proof, None, max_price)
+    }
+
+    /// Mint paying `current_price` in the curve's payment token plus a flat lamport fee
+    /// Burn share (if any) is burned from the buyer's payment tokens; `max_price` is in token units
+    pub fn mint_edition_dual_pay(
+        ctx: Context<MintEditionDualPay>,
+        max_price: u64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        // Pause, supply, reservation and period-cap checks
+        check_mintable(curve, &ctx.accounts.next_reservation, &ctx.accounts.platform_config)?;
+        check_mint_cooldown(curve, &ctx.accounts.mint_counter)?;
+
+        // Priced in payment token units; the lamport USD floor does not apply
+        let current_price = calculate_price(
+            curve,
+            curve.current_supply + 1, // Next edition number
+        )?;
+
+        require!(current_price <= max_price, BondingCurveError::SlippageExceeded);
+
+        msg!("Minting edition #{} at {} tokens + {} lamports fee", edition_number(curve, curve.current_supply + 1), current_price, curve.flat_fee);
+
+        // Primary payment in the payment token (less platform fee and burn share)
+        let net_proceeds = collect_token_payment(
+            curve,
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.payment_mint.to_account_info(),
+            &ctx.accounts.buyer_payment_account.to_account_info(),
+            &ctx.accounts.authority_payment_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.treasury_payment_account,
+            &ctx.accounts.platform_config,
+            &ctx.accounts.platform_payment_account,
+            &None,
+            &[],
+            &ctx.accounts.token_program.to_account_info(),
+            current_price,
+        )?;
+
+        // Flat protocol fee in lamports
+        if curve.flat_fee > 0 {
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.buyer.key(),
+                &ctx.accounts.fee_recipient.key(),
+                curve.flat_fee,
+            );
+            anchor_lang::solana_program::program::invoke(
+                &ix,
+                &[
+                    ctx.accounts.buyer.to_account_info(),
+                    ctx.accounts.fee_recipient.to_account_info(),
+                ],
+            )?;
+        }
+
+        deliver_edition(
+            curve,
+            ctx.accounts.buyer.key(),
+            Delivery {
+                edition_mint: ctx.accounts.edition_mint.to_account_info(),
+                destination: ctx.accounts.buyer_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint_receipt: &mut ctx.accounts.mint_receipt,
+                receipt_bump: ctx.bumps.mint_receipt,
+                mint_counter: &mut ctx.accounts.mint_counter,
+                counter_bump: ctx.bumps.mint_counter,
+            },
+            current_price,
+            net_proceeds,
+        )?;
+
+        msg!("Edition #{} minted with dual payment", edition_number(curve, curve.current_supply));
+
+        Ok(())
+    }
+
+    /// Mint into a curve-held escrow pending KYC (compliance-gated curves)
+    /// The edition stays in escrow until the compliance authority releases it
+    pub fn mint_edition_escrowed(
+        ctx: Context<MintEditionEscrowed>,
+        max_price: u64,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        require!(
+            curve.compliance_authority != Pubkey::default(),
+            BondingCurveError::EscrowNotEnabled
+        );
+
+        // Pause, supply, reservation and period-cap checks
+        check_mint_gates(curve, &ctx.accounts.next_reservation, &ctx.accounts.platform_config)?;
+        check_mint_cooldown(curve, &ctx.accounts.mint_counter)?;
+
+        let current_price = calculate_price(
+            curve,
+            curve.current_supply + 1, // Next edition number
+        )?;
+        let current_price = apply_usd_floor(curve, &ctx.accounts.price_feed, current_price)?;
+
+        // The edition token goes into the curve-owned escrow, not to the buyer
+        do_mint(
+            curve,
+            &ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.buyer.key(),
+            Payees {
+                authority: &ctx.accounts.authority_account,
+                treasury: &ctx.accounts.treasury,
+                incinerator: &ctx.accounts.incinerator,
+                platform_config: &ctx.accounts.platform_config,
+                platform_wallet: &ctx.accounts.platform_wallet,
+            },
+            Delivery {
+                edition_mint: ctx.accounts.edition_mint.to_account_info(),
+                destination: ctx.accounts.escrow_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                mint_receipt: &mut ctx.accounts.mint_receipt,
+                receipt_bump: ctx.bumps.mint_receipt,
+                mint_counter: &mut ctx.accounts.mint_counter,
+                counter_bump: ctx.bumps.mint_counter,
+            },
+            current_price,
+            0,
+            max_price,
+        )?;
+
+        let escrow = &mut ctx.accounts.edition_escrow;
+        escrow.bonding_curve = curve.key();
+        escrow.buyer = ctx.accounts.buyer.key();
+        escrow.edition_mint = ctx.accounts.edition_mint.key();
+        escrow.bump = ctx.bumps.edition_escrow;
+        curve.pending_escrows = curve.pending_escrows
+            .checked_add(1)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+        msg!("Edition #{} held in escrow for {}", edition_number(curve, curve.current_supply), ctx.accounts.buyer.key());
+
+        Ok(())
+    }
+
+    /// Release an escrowed edition to its buyer after KYC approval (compliance authority only)
+    /// Closes the escrow token account and record, returning rent to the buyer
+    pub fn release_edition(ctx: Context<ReleaseEdition>, buyer: Pubkey) -> Result<()> {
+        let curve = &ctx.accounts.bonding_curve;
+        let seeds = &[
+            b"bonding_curve",
+            curve.collection_mint.as_ref(),
+            &[curve.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: ctx.accounts.bonding_curve.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer),
+            ctx.accounts.escrow_token_account.amount,
+        )?;
+
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.buyer_account.to_account_info(),
+            authority: ctx.accounts.bonding_curve.to_account_info(),
+        };
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        ))?;
+
+        ctx.accounts.bonding_curve.pending_escrows -= 1;
+
+        msg!("Edition {} released to {}", ctx.accounts.edition_mint.key(), buyer);
+
+        Ok(())
+    }
+
+    /// Total lamports the curve raises at sellout (returned via return data)
+    /// Realized `total_volume` plus the price of every remaining edition
+    pub fn get_total_raise(ctx: Context<ViewCurve>) -> Result<u64> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        let total = curve.total_volume
+            .checked_add(sum_prices(curve, curve.current_supply + 1, curve.max_supply)?)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+        msg!("Total raise at sellout: {} lamports", total);
+
+        Ok(total)
+    }
+
+    /// Area under the price curve over minted supply (returned via return data)
+    /// Equals `total_volume` unless parameters changed mid-sale, so it can flag drift
+    pub fn get_auc(ctx: Context<ViewCurve>) -> Result<u64> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        let auc = sum_prices(curve, 1, curve.current_supply)?;
+
+        msg!("Area under curve: {} lamports (total volume {})", auc, curve.total_volume);
+
+        Ok(auc)
+    }
+
+    /// Current and pending authority, for displaying a handoff (returned via return data)
+    pub fn get_authority_info(ctx: Context<ViewCurve>) -> Result<AuthorityInfo> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        Ok(AuthorityInfo {
+            authority: curve.authority,
+            pending_authority: curve.pending_authority,
+        })
+    }
+
+    /// Whether a Bezier lookup exists for this curve and how many prices it holds
+    pub fn get_lookup_info(ctx: Context<GetLookupInfo>) -> Result<LookupInfo> {
+        let lookup_info = &ctx.accounts.bezier_lookup;
+
+        if lookup_info.data_is_empty() {
+            return Ok(LookupInfo { has_lookup: false, lookup_len: 0 });
+        }
+
+        let lookup = BezierPriceLookup::try_deserialize(&mut &lookup_info.try_borrow_data()?[..])?;
+
+        Ok(LookupInfo {
+            has_lookup: true,
+            lookup_len: lookup.prices.len() as u32,
+        })
+    }
+
+    /// Price sensitivity if 10% more supply were minted (returned via return data)
+    pub fn get_elasticity(ctx: Context<ViewCurve>) -> Result<ElasticityMetrics> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        require!(
+            curve.current_supply < curve.max_supply,
+            BondingCurveError::MaxSupplyReached
+        );
+
+        let next_edition = curve.current_supply + 1;
+        let current_price = calculate_price(curve, next_edition)?;
+
+        // 10% of current supply (at least one edition), capped at max supply
+        let delta_supply = (curve.current_supply / 10)
+            .max(1)
+            .min(curve.max_supply - next_edition);
+        let projected_price = calculate_price(curve, next_edition + delta_supply)?;
+
+        let delta_bps = if current_price > 0 {
+            ((projected_price.saturating_sub(current_price) as u128) * 10000
+                / current_price as u128) as u64
+        } else {
+            0
+        };
+
+        msg!("Elasticity: {} -> {} lamports (+{} bps over {} editions)", current_price, projected_price, delta_bps, delta_supply);
+
+        Ok(ElasticityMetrics {
+            current_price,
+            projected_price,
+            delta_supply,
+            delta_bps,
+        })
+    }
+
+    /// Curve type name as raw UTF-8 return data (e.g. "Linear"), no Borsh framing
+    pub fn get_curve_type(ctx: Context<ViewCurve>) -> Result<()> {
+        let name = ctx.accounts.bonding_curve.curve_type.name();
+
+        anchor_lang::solana_program::program::set_return_data(name.as_bytes());
+
+        msg!("Curve type: {}", name);
+
+        Ok(())
+    }
+
+    /// How many editions a lamport budget buys from the current supply (returned via return data)
+    pub fn editions_for_budget(ctx: Context<ViewCurve>, budget: u64) -> Result<BudgetQuote> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        let (editions, total_cost) = budget_quote(curve, budget)?;
+
+        msg!("Budget {} buys {} editions for {} lamports", budget, editions, total_cost);
+
+        Ok(BudgetQuote { editions, total_cost })
+    }
+
+    /// Cost and price impact of minting `quantity` editions from the current supply
+    /// (returned via return data); impact is last vs first price in basis points
+    pub fn get_batch_quote(ctx: Context<ViewCurve>, quantity: u32) -> Result<BatchQuote> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        require!(
+            quantity > 0 && quantity <= curve.max_supply - curve.current_supply,
+            BondingCurveError::InvalidQuantity
+        );
+
+        let first_edition = curve.current_supply + 1;
+        let last_edition = curve.current_supply + quantity;
+
+        let total_cost = batch_cost(curve, quantity)?;
+
+        let first_price = calculate_price(curve, first_edition)?;
+        let last_price = calculate_price(curve, last_edition)?;
+        let average_price = total_cost / quantity as u64;
+        let impact_bps = if first_price > 0 {
+            ((last_price.saturating_sub(first_price) as u128) * 10000
+                / first_price as u128) as u64
+        } else {
+            0
+        };
+
+        msg!("Batch of {}: {} lamports total, {} avg (+{} bps)", quantity, total_cost, average_price, impact_bps);
+
+        Ok(BatchQuote {
+            total_cost,
+            average_price,
+            first_price,
+            last_price,
+            impact_bps,
+        })
+    }
+
+    /// Total lamports to mint the next `quantity` editions (returned via return data)
+    /// Pure read; fails with `MaxSupplyReached` if the batch would pass max_supply
+    pub fn quote_batch(ctx: Context<ViewCurve>, quantity: u32) -> Result<u64> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        require!(quantity > 0, BondingCurveError::InvalidQuantity);
+        require!(
+            quantity <= curve.max_supply - curve.current_supply,
+            BondingCurveError::MaxSupplyReached
+        );
+
+        let total = batch_cost(curve, quantity)?;
+
+        msg!("Next {} editions cost {} lamports", quantity, total);
+
+        Ok(total)
+    }
+
+    /// Lamports to mint every edition from the next one through `target_edition`
+    /// (returned via return data)
+    pub fn cost_to_reach(ctx: Context<ViewCurve>, target_edition: u32) -> Result<u64> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        require!(
+            target_edition > curve.current_supply && target_edition <= curve.max_supply,
+            BondingCurveError::InvalidEdition
+        );
+
+        let total_cost = sum_prices(curve, curve.current_supply + 1, target_edition)?;
+
+        msg!("Cost to reach edition #{}: {} lamports", target_edition, total_cost);
+
+        Ok(total_cost)
+    }
+
+    /// A wallet's average lamports paid per edition minted (returned via return data)
+    pub fn get_holder_avg_cost(ctx: Context<ViewHolder>, buyer: Pubkey) -> Result<u64> {
+        let counter = &ctx.accounts.mint_counter;
+
+        let avg_cost = if counter.minted > 0 {
+            counter.total_paid / counter.minted as u64
+        } else {
+            0
+        };
+
+        msg!("Holder {} average cost: {} lamports over {} editions", buyer, avg_cost, counter.minted);
+
+        Ok(avg_cost)
+    }
+
+    /// Check the treasury vault covers the required reserve for outstanding editions
+    /// Permissionless; fails with `InsufficientReserve` when the vault is short
+    pub fn assert_reserve_solvent(ctx: Context<ViewVault>) -> Result<()> {
+        let reserve = required_reserve(&ctx.accounts.bonding_curve)?;
+        let balance = ctx.accounts.treasury.lamports();
+
+        require!(balance >= reserve, BondingCurveError::InsufficientReserve);
+
+        msg!("Vault solvent: {} lamports against {} required", balance, reserve);
+
+        Ok(())
+    }
+
+    /// How many editions the treasury vault can currently buy back (returned via return data)
+    /// Buy-backs walk down the curve from the top; accrued platform fees, prepaid
+    /// reservations and the vault's rent-exempt minimum are not available
+    pub fn get_buyback_runway(ctx: Context<ViewVault>) -> Result<u32> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        let rent_floor = Rent::get()?.minimum_balance(ctx.accounts.treasury.data_len());
+        let available = ctx.accounts.treasury.lamports()
+            .saturating_sub(curve.accrued_platform_fees)
+            .saturating_sub(curve.reserved_proceeds)
+            .saturating_sub(rent_floor);
+        let runway = buyback_runway(curve, available)?;
+
+        msg!("Vault can buy back {} of {} editions", runway, curve.current_supply);
+
+        Ok(runway)
+    }
+
+    /// Advisory increment to sell out by `target_timestamp` (returned via return data)
+    /// Assumes sales continue at the velocity observed since creation, and that
+    /// scaling the increment scales demand inversely: the current increment is
+    /// multiplied by projected sales over remaining supply. Pure read.
+    pub fn suggest_increment(ctx: Context<ViewCurve>, target_timestamp: i64) -> Result<u64> {
+        let curve = &ctx.accounts.bonding_curve;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(target_timestamp > now, BondingCurveError::InvalidTargetTime);
+
+        let remaining = (curve.max_supply - curve.current_supply) as u128;
+        let elapsed = now.saturating_sub(curve.created_at);
+
+        // No sales history (or nothing left to sell) gives no signal
+        if remaining == 0 || curve.current_supply == 0 || elapsed <= 0 {
+            return Ok(curve.price_increment);
+        }
+
+        // Editions expected by the target at the observed velocity
+        let projected = curve.current_supply as u128 * (target_timestamp - now) as u128
+            / elapsed as u128;
+        let suggested = curve.price_increment as u128 * projected / remaining;
+        let suggested = u64::try_from(suggested).unwrap_or(u64::MAX);
+
+        msg!("Projected {} of {} remaining editions by target; suggested increment {}", projected, remaining, suggested);
+
+        Ok(suggested)
+    }
+
+    /// Provenance of a minted edition from its receipt (returned via return data)
+    pub fn get_edition_info(ctx: Context<ViewEdition>, edition_mint: Pubkey) -> Result<EditionInfo> {
+        let receipt = &ctx.accounts.mint_receipt;
+
+        msg!("Edition {} is #{} minted at slot {}", edition_mint, receipt.edition, receipt.slot);
+
+        Ok(EditionInfo {
+            edition: receipt.edition,
+            price_paid: receipt.price_paid,
+            slot: receipt.slot,
+        })
+    }
+
+    /// Share of max supply minted, in basis points (returned via return data)
+    /// 0 when max_supply is 0, capped at 10000
+    pub fn get_progress_bps(ctx: Context<ViewCurve>) -> Result<u16> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        let progress_bps = if curve.max_supply > 0 {
+            (curve.current_supply as u64 * 10000 / curve.max_supply as u64).min(10000) as u16
+        } else {
+            0
+        };
+
+        msg!("Progress: {} bps", progress_bps);
+
+        Ok(progress_bps)
+    }
+
+    /// Itemized split of the next mint's payment (returned via return data)
+    /// Assumes a referred mint; the burn share applies to what is left after the
+    /// platform fee and referral reward, as when the mint is collected
+    pub fn get_fee_breakdown(ctx: Context<ViewFeeBreakdown>, path: MintPath) -> Result<FeeBreakdown> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        require!(
+            curve.current_supply < curve.max_supply,
+            BondingCurveError::MaxSupplyReached
+        );
+
+        // Priced as `path` prices it: the time premium is part of the curve price,
+        // coupons only apply to mint_edition and the USD floor only to lamport prices
+        let price = calculate_price(curve, curve.current_supply + 1)?;
+        let price = match (&ctx.accounts.coupon, path) {
+            (Some(coupon), MintPath::Standard) => price - coupon_discount(coupon, price)?,
+            _ => price,
+        };
+        let price = if path == MintPath::DualPay || curve.payment_mint.is_some() {
+            price
+        } else {
+            apply_usd_floor(curve, &ctx.accounts.price_feed, price)?
+        };
+
+        fee_breakdown(curve, &ctx.accounts.platform_config, path, price)
+    }
+
+    /// Supply, next price and volume in one call (returned via return data)
+    /// `next_price` is None once the curve has sold out
+    pub fn get_curve_state(ctx: Context<ViewCurve>) -> Result<CurveState> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        let next_price = if curve.current_supply < curve.max_supply {
+            Some(calculate_price(curve, curve.current_supply + 1)?)
+        } else {
+            None
+        };
+
+        Ok(CurveState {
+            current_supply: curve.current_supply,
+            max_supply: curve.max_supply,
+            next_price,
+            total_volume: curve.total_volume,
+        })
+    }
+
+    /// Lowest supply at which the sell-back price reaches `paid_price` (returned via return data)
+    /// Binary search assuming the curve is non-decreasing in supply
+    pub fn get_breakeven_supply(ctx: Context<ViewCurve>, paid_price: u64) -> Result<u32> {
+        let curve = &ctx.accounts.bonding_curve;
+
+        require!(curve.max_supply > 0, BondingCurveError::InvalidMaxSupply);
+        require!(
+            calculate_price(curve, curve.max_supply)? >= paid_price,
+            BondingCurveError::PriceUnreachable
+        );
+
+        let (mut low, mut high) = (1u32, curve.max_supply);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if calculate_price(curve, mid)? >= paid_price {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        msg!("Break-even for {} lamports at supply {}", paid_price, low);
+
+        Ok(low)
+    }
+}
+
+// Burn share of a single edition's price (rounded down, remainder to the creator)
+// Always computed per edition so multi-edition totals equal the sum of single mints
+fn burn_share(curve: &BondingCurve, price: u64) -> Result<u64> {
+    let share = (price as u128)
+        .checked_mul(curve.burn_bps as u128)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?
+        / 10000;
+
+    Ok(share as u64)
+}
+
+// Referrer's share of a referred mint's price (rounded down in the creator's favor)
+fn referral_share(curve: &BondingCurve, price: u64) -> Result<u64> {
+    let share = (price as u128)
+        .checked_mul(curve.referral_bps as u128)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?
+        / 10000;
+
+    Ok(share as u64)
+}
+
+// Platform's fee share of a lamport `price` (0 until the platform config exists)
+fn platform_fee_share(platform_config: &UncheckedAccount, price: u64) -> Result<u64> {
+    if platform_config.data_is_empty() {
+        return Ok(0);
+    }
+
+    let config = PlatformConfig::try_deserialize(&mut &platform_config.try_borrow_data()?[..])?;
+    let share = (price as u128)
+        .checked_mul(config.platform_fee_bps as u128)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?
+        / 10000;
+
+    Ok(share as u64)
+}
+
+// How a mint payment divides: platform fee off the top, then the burn share of
+// what is left; the rest (`net`) goes to the creator, treasury or royalty split
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PaymentSplit {
+    platform_fee: u64,
+    burn: u64,
+    net: u64,
+}
+
+fn split_payment(curve: &BondingCurve, platform_config: &UncheckedAccount, price: u64) -> Result<PaymentSplit> {
+    let platform_fee = platform_fee_share(platform_config, price)?;
+    let burn = burn_share(curve, price - platform_fee)?;
+
+    Ok(PaymentSplit {
+        platform_fee,
+        burn,
+        net: price - platform_fee - burn,
+    })
+}
+
+// Route a mint payment: platform fee off the top, burn share of the rest to the
+// incinerator, remainder to the creator (or to the treasury vault when the curve
+// holds its proceeds, or across the royalty split's recipients when one is
+// configured); returns that remainder
+#[allow(clippy::too_many_arguments)]
+fn collect_payment<'info>(
+    curve: &mut BondingCurve,
+    buyer: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    treasury: &Option<SystemAccount<'info>>,
+    incinerator: &Option<UncheckedAccount<'info>>,
+    platform_config: &UncheckedAccount<'info>,
+    platform_wallet: &Option<UncheckedAccount<'info>>,
+    royalty_split: &Option<Account<'info, RoyaltySplit>>,
+    royalty_recipients: &[AccountInfo<'info>],
+    price: u64,
+) -> Result<u64> {
+    let split = split_payment(curve, platform_config, price)?;
+    pay_platform_fee(curve, buyer, platform_config, platform_wallet, treasury, split.platform_fee)?;
+
+    route_payment(
+        curve,
+        buyer,
+        authority,
+        treasury,
+        incinerator,
+        royalty_split,
+        royalty_recipients,
+        price - split.platform_fee,
+        split.burn,
+    )
+}
+
+// collect_payment with the burn share already worked out (summed per edition for batches)
+#[allow(clippy::too_many_arguments)]
+fn route_payment<'info>(
+    curve: &mut BondingCurve,
+    buyer: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    treasury: &Option<SystemAccount<'info>>,
+    incinerator: &Option<UncheckedAccount<'info>>,
+    royalty_split: &Option<Account<'info, RoyaltySplit>>,
+    royalty_recipients: &[AccountInfo<'info>],
+    price: u64,
+    burn_amount: u64,
+) -> Result<u64> {
+    // Token-priced curves must be paid through the token path
+    require!(curve.payment_mint.is_none(), BondingCurveError::PaymentMintMismatch);
+
+    let net = price - burn_amount;
+
+    if burn_amount > 0 {
+        let incinerator = incinerator
+            .as_ref()
+            .ok_or(BondingCurveError::IncineratorRequired)?;
+
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &buyer.key(),
+            &incinerator.key(),
+            burn_amount,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[buyer.clone(), incinerator.to_account_info()],
+        )?;
+
+        curve.total_burned = curve.total_burned
+            .checked_add(burn_amount)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    }
+
+    // Curve-held proceeds go to the treasury vault instead of the creator
+    if curve.proceeds_to_treasury() {
+        let treasury = treasury
+            .as_ref()
+            .ok_or(BondingCurveError::TreasuryRequired)?;
+
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &buyer.key(),
+            &treasury.key(),
+            net,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[buyer.clone(), treasury.to_account_info()],
+        )?;
+
+        return Ok(net);
+    }
+
+    if curve.royalty_split_enabled {
+        let split = royalty_split
+            .as_ref()
+            .ok_or(BondingCurveError::RoyaltySplitRequired)?;
+        pay_royalty_split(buyer, split, royalty_recipients, net)?;
+        return Ok(net);
+    }
+
+    pay_creator(buyer, authority, net)?;
+    Ok(net)
+}
+
+// Move an already-computed platform `fee` from the buyer (no-op when 0)
+// Curves holding proceeds in the treasury accrue the fee there for withdraw_fees
+fn pay_platform_fee<'info>(
+    curve: &mut BondingCurve,
+    buyer: &AccountInfo<'info>,
+    platform_config: &UncheckedAccount<'info>,
+    platform_wallet: &Option<UncheckedAccount<'info>>,
+    treasury: &Option<SystemAccount<'info>>,
+    fee: u64,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+
+    if curve.proceeds_to_treasury() {
+        let treasury = treasury
+            .as_ref()
+            .ok_or(BondingCurveError::TreasuryRequired)?;
+
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &buyer.key(),
+            &treasury.key(),
+            fee,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[buyer.clone(), treasury.to_account_info()],
+        )?;
+
+        curve.accrued_platform_fees = curve.accrued_platform_fees
+            .checked_add(fee)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+        return Ok(());
+    }
+
+    let config = PlatformConfig::try_deserialize(&mut &platform_config.try_borrow_data()?[..])?;
+    let platform_wallet = platform_wallet
+        .as_ref()
+        .ok_or(BondingCurveError::PlatformWalletRequired)?;
+    require_keys_eq!(
+        platform_wallet.key(),
+        config.platform_wallet,
+        BondingCurveError::PlatformWalletRequired
+    );
+
+    let ix = anchor_lang::solana_program::system_instruction::transfer(
+        &buyer.key(),
+        &platform_wallet.key(),
+        fee,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &ix,
+        &[buyer.clone(), platform_wallet.to_account_info()],
+    )?;
+
+    Ok(())
+}
+
+// Copy a creator profile's fee settings onto a curve
+// Crowdfunds refund the full price, so they cannot take burn or referral shares,
+// and refund-window curves cannot burn
+fn apply_creator_profile(curve: &mut BondingCurve, profile: &CreatorProfile) -> Result<()> {
+    require!(
+        curve.min_goal == 0 || (profile.burn_bps == 0 && profile.referral_bps == 0),
+        BondingCurveError::InvalidFeeBps
+    );
+    require!(
+        curve.refund_window == 0 || profile.burn_bps == 0,
+        BondingCurveError::InvalidFeeBps
+    );
+
+    curve.burn_bps = profile.burn_bps;
+    curve.referral_bps = profile.referral_bps;
+
+    Ok(())
+}
+
+// Editions `available` lamports buy back, one sell-back price at a time from the
+// top of the curve down; sell-backs never pay out more than the sales kept, so
+// the tracked buy-back liability caps what the walk can cost in total
+fn buyback_runway(curve: &BondingCurve, available: u64) -> Result<u32> {
+    if curve.buyback_liability <= available {
+        return Ok(curve.current_supply);
+    }
+
+    let mut liability_left = curve.buyback_liability;
+    let mut spent = 0u64;
+    let mut runway = 0;
+    for supply in (1..=curve.current_supply).rev() {
+        let cost = calculate_price(curve, supply)?.min(liability_left);
+        spent = spent.saturating_add(cost);
+        if spent > available {
+            break;
+        }
+        liability_left -= cost;
+        runway += 1;
+    }
+
+    Ok(runway)
+}
+
+// Count one mint_reserve edition against `max_reserve_mints`; it takes no public
+// edition, so `current_supply` and the public price stay where they are
+fn record_reserve_mint(curve: &mut BondingCurve) -> Result<()> {
+    require!(
+        curve.reserved_minted < curve.max_reserve_mints,
+        BondingCurveError::ReserveAllocationExceeded
+    );
+    curve.reserved_minted += 1;
+
+    Ok(())
+}
+
+// What an unexpired price hold charges at `current_price`: the lower of the two,
+// and the shortfall below the curve price given up to honour it
+fn settle_price_hold(reservation: &PriceReservation, current_price: u64, now: i64) -> Result<(u64, u64)> {
+    require!(now <= reservation.expires_at, BondingCurveError::ReservationExpired);
+
+    let charged = current_price.min(reservation.price);
+    Ok((charged, current_price - charged))
+}
+
+// Take a coupon's discount off `price` and consume one of its uses
+fn apply_coupon(coupon: &mut Option<Account<Coupon>>, price: u64) -> Result<u64> {
+    let Some(coupon) = coupon.as_mut() else {
+        return Ok(price);
+    };
+
+    let discount = coupon_discount(coupon, price)?;
+    coupon.uses_remaining -= 1;
+
+    msg!("Coupon applied: {} lamports off ({} uses left)", discount, coupon.uses_remaining);
+
+    Ok(price - discount)
+}
+
+// What a coupon takes off `price`; fails once its uses are spent
+fn coupon_discount(coupon: &Coupon, price: u64) -> Result<u64> {
+    require!(coupon.uses_remaining > 0, BondingCurveError::CouponExhausted);
+
+    let discount = (price as u128)
+        .checked_mul(coupon.discount_bps as u128)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?
+        / 10000;
+
+    Ok(discount as u64)
+}
+
+// How `path` divides a mint payment of `price`: the referral reward is paid
+// first, split_payment divides the rest; the dual-pay lamport flat fee is
+// charged on top of the price, not out of it
+fn fee_breakdown(
+    curve: &BondingCurve,
+    platform_config: &UncheckedAccount,
+    path: MintPath,
+    price: u64,
+) -> Result<FeeBreakdown> {
+    let referral = if path == MintPath::Referral {
+        referral_share(curve, price)?
+    } else {
+        0
+    };
+    let split = split_payment(curve, platform_config, price - referral)?;
+    let flat_fee = if path == MintPath::DualPay { curve.flat_fee } else { 0 };
+
+    Ok(FeeBreakdown {
+        price,
+        burn: split.burn,
+        net_to_creator: split.net,
+        platform_fee: split.platform_fee,
+        referral,
+        flat_fee,
+    })
+}
+
+// Pay each split recipient its bps share of `amount`; the last recipient
+// takes the rounding remainder so the full amount is always distributed
+fn pay_royalty_split<'info>(
+    buyer: &AccountInfo<'info>,
+    split: &RoyaltySplit,
+    recipients: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    require!(
+        recipients.len() >= split.recipients.len(),
+        BondingCurveError::RoyaltyRecipientMismatch
+    );
+
+    let last = split.recipients.len() - 1;
+    let mut remaining = amount;
+
+    for (i, (entry, recipient)) in split.recipients.iter().zip(recipients).enumerate() {
+        require_keys_eq!(recipient.key(), entry.recipient, BondingCurveError::RoyaltyRecipientMismatch);
+
+        let share = if i == last {
+            remaining
+        } else {
+            ((amount as u128)
+                .checked_mul(entry.bps as u128)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+                / 10000) as u64
+        };
+        remaining -= share;
+
+        // A recipient minting for itself keeps its share
+        if share == 0 || recipient.key() == buyer.key() {
+            continue;
+        }
+
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &buyer.key(),
+            &recipient.key(),
+            share,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[buyer.clone(), recipient.clone()],
+        )?;
+    }
+
+    Ok(())
+}
+
+// Collect a payment in the curve's payment token: platform fee off the top,
+// burn share of the rest burned, remainder into the treasury vault's token
+// account when proceeds are held by the curve, otherwise to the creator's token
+// account or, when a royalty split is passed, across its recipients' accounts
+// Returns the tokens kept in the vault (0 when paid out)
+#[allow(clippy::too_many_arguments)]
+fn collect_token_payment<'info>(
+    curve: &mut BondingCurve,
+    buyer: &AccountInfo<'info>,
+    payment_mint: &AccountInfo<'info>,
+    buyer_payment_account: &AccountInfo<'info>,
+    authority_payment_account: &Option<Account<'info, TokenAccount>>,
+    treasury: &Option<SystemAccount<'info>>,
+    treasury_payment_account: &Option<Account<'info, TokenAccount>>,
+    platform_config: &UncheckedAccount<'info>,
+    platform_payment_account: &Option<Account<'info, TokenAccount>>,
+    royalty_split: &Option<Account<'info, RoyaltySplit>>,
+    royalty_recipients: &[AccountInfo<'info>],
+    token_program: &AccountInfo<'info>,
+    price: u64,
+) -> Result<u64> {
+    let payment = split_payment(curve, platform_config, price)?;
+    collect_token_platform_fee(
+        platform_config,
+        buyer,
+        payment_mint.key(),
+        buyer_payment_account,
+        platform_payment_account,
+        token_program,
+        payment.platform_fee,
+    )?;
+    let burn_amount = payment.burn;
+
+    if burn_amount > 0 {
+        let cpi_accounts = Burn {
+            mint: payment_mint.clone(),
+            from: buyer_payment_account.clone(),
+            authority: buyer.clone(),
+        };
+        token::burn(CpiContext::new(token_program.clone(), cpi_accounts), burn_amount)?;
+
+        curve.total_burned = curve.total_burned
+            .checked_add(burn_amount)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    }
+
+    if curve.proceeds_to_treasury() {
+        let treasury = treasury
+            .as_ref()
+            .ok_or(BondingCurveError::TreasuryRequired)?;
+        let vault = vault_payment_account(treasury.key(), payment_mint.key(), treasury_payment_account)?;
+
+        let cpi_accounts = Transfer {
+            from: buyer_payment_account.clone(),
+            to: vault.to_account_info(),
+            authority: buyer.clone(),
+        };
+        token::transfer(CpiContext::new(token_program.clone(), cpi_accounts), payment.net)?;
+
+        return Ok(payment.net);
+    }
+
+    if let Some(split) = royalty_split {
+        pay_token_royalty_split(
+            buyer,
+            split,
+            royalty_recipients,
+            payment_mint.key(),
+            buyer_payment_account,
+            token_program,
+            payment.net,
+        )?;
+        return Ok(0);
+    }
+
+    let recipient = authority_payment_account
+        .as_ref()
+        .ok_or(BondingCurveError::PaymentAccountsRequired)?;
+    require_keys_eq!(recipient.owner, curve.authority, BondingCurveError::Unauthorized);
+    require_keys_eq!(recipient.mint, payment_mint.key(), BondingCurveError::PaymentMintMismatch);
+
+    let cpi_accounts = Transfer {
+        from: buyer_payment_account.clone(),
+        to: recipient.to_account_info(),
+        authority: buyer.clone(),
+    };
+    token::transfer(CpiContext::new(token_program.clone(), cpi_accounts), payment.net)?;
+
+    Ok(0)
+}
+
+// The treasury vault's associated token account for the payment mint; it holds
+// token proceeds the way the vault itself holds lamports
+fn vault_payment_account<'a, 'info>(
+    treasury: Pubkey,
+    payment_mint: Pubkey,
+    account: &'a Option<Account<'info, TokenAccount>>,
+) -> Result<&'a Account<'info, TokenAccount>> {
+    let account = account
+        .as_ref()
+        .ok_or(BondingCurveError::PaymentAccountsRequired)?;
+    require_keys_eq!(
+        account.key(),
+        anchor_spl::associated_token::get_associated_token_address(&treasury, &payment_mint),
+        BondingCurveError::VaultPaymentAccountMismatch
+    );
+
+    Ok(account)
+}
+
+// Pay `amount` out of the treasury vault: lamports to `recipient`, or on a
+// token-priced curve from the vault's token account into `recipient_payment_account`
+#[allow(clippy::too_many_arguments)]
+fn pay_from_treasury<'info>(
+    curve: &Account<'info, BondingCurve>,
+    treasury: &AccountInfo<'info>,
+    treasury_bump: u8,
+    recipient: &AccountInfo<'info>,
+    treasury_payment_account: &Option<Account<'info, TokenAccount>>,
+    recipient_payment_account: &Option<Account<'info, TokenAccount>>,
+    token_program: Option<AccountInfo<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let curve_key = curve.key();
+    let seeds = &[
+        b"treasury",
+        curve_key.as_ref(),
+        &[treasury_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let Some(payment_mint) = curve.payment_mint else {
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &treasury.key(),
+            &recipient.key(),
+            amount,
+        );
+        anchor_lang::solana_program::program::invoke_signed(
+            &ix,
+            &[treasury.clone(), recipient.clone()],
+            signer,
+        )?;
+        return Ok(());
+    };
+
+    let vault = vault_payment_account(treasury.key(), payment_mint, treasury_payment_account)?;
+    require!(vault.amount >= amount, BondingCurveError::InsufficientTreasuryBalance);
+    let (Some(recipient_account), Some(token_program)) = (recipient_payment_account, token_program) else {
+        return err!(BondingCurveError::PaymentAccountsRequired);
+    };
+    require_keys_eq!(recipient_account.owner, recipient.key(), BondingCurveError::Unauthorized);
+    require_keys_eq!(recipient_account.mint, payment_mint, BondingCurveError::PaymentMintMismatch);
+
+    let cpi_accounts = Transfer {
+        from: vault.to_account_info(),
+        to: recipient_account.to_account_info(),
+        authority: treasury.clone(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(token_program, cpi_accounts, signer),
+        amount,
+    )
+}
+
+// pay_royalty_split for token payments: each recipient account must be a token
+// account of the payment mint owned by the split entry's wallet
+fn pay_token_royalty_split<'info>(
+    buyer: &AccountInfo<'info>,
+    split: &RoyaltySplit,
+    recipients: &[AccountInfo<'info>],
+    payment_mint: Pubkey,
+    buyer_payment_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        recipients.len() >= split.recipients.len(),
+        BondingCurveError::RoyaltyRecipientMismatch
+    );
+
+    let last = split.recipients.len() - 1;
+    let mut remaining = amount;
+
+    for (i, (entry, recipient)) in split.recipients.iter().zip(recipients).enumerate() {
+        require_keys_eq!(*recipient.owner, token_program.key(), BondingCurveError::RoyaltyRecipientMismatch);
+        let recipient_account = TokenAccount::try_deserialize(&mut &recipient.try_borrow_data()?[..])?;
+        require_keys_eq!(recipient_account.owner, entry.recipient, BondingCurveError::RoyaltyRecipientMismatch);
+        require_keys_eq!(recipient_account.mint, payment_mint, BondingCurveError::PaymentMintMismatch);
+
+        let share = if i == last {
+            remaining
+        } else {
+            ((amount as u128)
+                .checked_mul(entry.bps as u128)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+                / 10000) as u64
+        };
+        remaining -= share;
+
+        // A recipient minting for itself keeps its share
+        if share == 0 || entry.recipient == buyer.key() {
+            continue;
+        }
+
+        let cpi_accounts = Transfer {
+            from: buyer_payment_account.clone(),
+            to: recipient.clone(),
+            authority: buyer.clone(),
+        };
+        token::transfer(CpiContext::new(token_program.clone(), cpi_accounts), share)?;
+    }
+
+    Ok(())
+}
+
+// Pay an already-computed platform `fee` in the payment token into the platform
+// wallet's token account (no-op when 0)
+fn collect_token_platform_fee<'info>(
+    platform_config: &UncheckedAccount<'info>,
+    buyer: &AccountInfo<'info>,
+    payment_mint: Pubkey,
+    buyer_payment_account: &AccountInfo<'info>,
+    platform_payment_account: &Option<Account<'info, TokenAccount>>,
+    token_program: &AccountInfo<'info>,
+    fee: u64,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+
+    let config = PlatformConfig::try_deserialize(&mut &platform_config.try_borrow_data()?[..])?;
+    let platform_payment_account = platform_payment_account
+        .as_ref()
+        .ok_or(BondingCurveError::PlatformWalletRequired)?;
+    require_keys_eq!(
+        platform_payment_account.owner,
+        config.platform_wallet,
+        BondingCurveError::PlatformWalletRequired
+    );
+    require_keys_eq!(
+        platform_payment_account.mint,
+        payment_mint,
+        BondingCurveError::PaymentMintMismatch
+    );
+
+    let cpi_accounts = Transfer {
+        from: buyer_payment_account.clone(),
+        to: platform_payment_account.to_account_info(),
+        authority: buyer.clone(),
+    };
+    token::transfer(CpiContext::new(token_program.clone(), cpi_accounts), fee)
+}
+
+// Transfer the mint payment to the creator
+// Self-mints by the authority skip the transfer since no funds move
+fn pay_creator<'info>(
+    buyer: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if buyer.key() == authority.key() {
+        msg!("Self-mint by authority: skipping payment transfer");
+        return Ok(());
+    }
+
+    let ix = anchor_lang::solana_program::system_instruction::transfer(
+        &buyer.key(),
+        &authority.key(),
+        amount,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &ix,
+        &[buyer.clone(), authority.clone()],
+    )?;
+
+    Ok(())
+}
+
+// Domain tag prefixed to every permit, so the signature means nothing elsewhere
+const PERMIT_DOMAIN: &[u8] = b"bonding_curve:mint_with_permit";
+
+// Bytes a buyer signs to authorize mint_with_permit: this program, the curve,
+// the edition mint and the relayer allowed to submit it
+#[allow(clippy::too_many_arguments)]
+fn permit_message(
+    curve: &Pubkey,
+    edition_mint: &Pubkey,
+    buyer: &Pubkey,
+    relayer: &Pubkey,
+    max_price: u64,
+    expires_at: i64,
+    nonce: u64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(PERMIT_DOMAIN.len() + 32 * 5 + 8 + 8 + 8);
+    message.extend_from_slice(PERMIT_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(curve.as_ref());
+    message.extend_from_slice(edition_mint.as_ref());
+    message.extend_from_slice(buyer.as_ref());
+    message.extend_from_slice(relayer.as_ref());
+    message.extend_from_slice(&max_price.to_le_bytes());
+    message.extend_from_slice(&expires_at.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message
+}
+
+// Lamports in a permit deposit above its rent-exempt minimum
+fn permit_deposit_balance(deposit: &AccountInfo) -> Result<u64> {
+    let rent_floor = Rent::get()?.minimum_balance(deposit.data_len());
+    Ok(deposit.lamports().saturating_sub(rent_floor))
+}
+
+// Check that the previous instruction is an ed25519 verification of `message` by `signer`
+#[allow(deprecated)]
+fn verify_ed25519_permit(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    use anchor_lang::solana_program::sysvar::instructions::{
+        load_current_index_checked, load_instruction_at_checked,
+    };
+
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, BondingCurveError::InvalidPermit);
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+
+    require_keys_eq!(ix.program_id, ED25519_PROGRAM_ID, BondingCurveError::InvalidPermit);
+    require!(ix.accounts.is_empty(), BondingCurveError::InvalidPermit);
+
+    // Layout: [num_signatures, padding, offsets (7 x u16)], then signature/pubkey/message
+    let data = &ix.data;
+    require!(data.len() >= 16 && data[0] == 1, BondingCurveError::InvalidPermit);
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+
+    // All offsets must point into this same instruction
+    let current_ix = u16::MAX;
+    require!(
+        read_u16(4) == current_ix && read_u16(8) == current_ix && read_u16(14) == current_ix,
+        BondingCurveError::InvalidPermit
+    );
+
+    let pubkey_offset = read_u16(6) as usize;
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+
+    let pubkey = data
+        .get(pubkey_offset..pubkey_offset + 32)
+        .ok_or(BondingCurveError::InvalidPermit)?;
+    let signed = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(BondingCurveError::InvalidPermit)?;
+
+    require!(pubkey == signer.as_ref(), BondingCurveError::InvalidPermit);
+    require!(signed == message, BondingCurveError::InvalidPermit);
+
+    Ok(())
+}
+
+// Shared body of the Merkle-gated mints: verify `leaf` against `merkle_root`,
+// enforce the per-wallet cap if the leaf carries one, then mint as usual
+fn mint_from_allowlist(
+    ctx: Context<MintEditionAllowlisted>,
+    leaf: [u8; 32],
+    proof: &[[u8; 32]],
+    max_qty: Option<u32>,
+    max_price: u64,
+) -> Result<()> {
+    let purchase = &mut ctx.accounts.purchase;
+    let curve = &mut purchase.bonding_curve;
+
+    let root = curve.merkle_root.ok_or(BondingCurveError::AllowlistNotConfigured)?;
+    require!(
+        verify_merkle_proof(proof, root, leaf),
+        BondingCurveError::NotWhitelisted
+    );
+
+    if let Some(max_qty) = max_qty {
+        require!(
+            purchase.mint_counter.minted < max_qty,
+            BondingCurveError::AllowlistQuantityExceeded
+        );
+    }
+
+    // Pause, supply, reservation and period-cap checks
+    check_mintable(curve, &purchase.next_reservation, &purchase.platform_config)?;
+    check_mint_cooldown(curve, &purchase.mint_counter)?;
+
+    let current_price = calculate_price(
+        curve,
+        curve.current_supply + 1, // Next edition number
+    )?;
+    let current_price = apply_usd_floor(curve, &purchase.price_feed, current_price)?;
+
+    purchase.mint(&ctx.bumps.purchase, current_price, 0, max_price)?;
+
+    let edition = edition_number(&purchase.bonding_curve, purchase.bonding_curve.current_supply);
+    match max_qty {
+        Some(max_qty) => msg!("Edition #{} minted ({} of {} allowlisted)", edition, purchase.mint_counter.minted, max_qty),
+        None => msg!("Edition #{} minted (whitelisted)", edition),
+    }
+
+    Ok(())
+}
+
+// Allowlist leaf committing a wallet and its maximum quantity
+fn allowlist_leaf(wallet: &Pubkey, max_qty: u32) -> [u8; 32] {
+    hashv(&[&[0u8], wallet.as_ref(), &max_qty.to_le_bytes()]).to_bytes()
+}
+
+// Whitelist leaf committing only a wallet
+fn whitelist_leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[&[0u8], wallet.as_ref()]).to_bytes()
+}
+
+// Verify a sorted-pair sha256 Merkle proof (leaves and nodes are domain-separated)
+fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        hashv(&[&[1u8], &left, &right]).to_bytes()
+    });
+    computed == root
+}
+
+// Platform-wide kill switch; a missing config means no global pause
+fn ensure_not_globally_paused(platform_config: &UncheckedAccount) -> Result<()> {
+    if platform_config.data_is_empty() {
+        return Ok(());
+    }
+
+    let config = PlatformConfig::try_deserialize(&mut &platform_config.try_borrow_data()?[..])?;
+    require!(!config.paused_globally, BondingCurveError::GloballyPaused);
+
+    Ok(())
+}
+
+// Count a mint against the current period, rolling into a new period when due
+fn consume_period_cap(curve: &mut BondingCurve) -> Result<()> {
+    if curve.period_secs == 0 {
+        return Ok(());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let elapsed = now.saturating_sub(curve.period_start);
+    if elapsed >= curve.period_secs {
+        // Keep periods aligned to the original start
+        curve.period_start = now - elapsed % curve.period_secs;
+        curve.period_minted = 0;
+    }
+
+    require!(
+        curve.period_minted < curve.period_cap,
+        BondingCurveError::PeriodCapReached
+    );
+    curve.period_minted += 1;
+
+    Ok(())
+}
+
+// Raise a lamport price to the curve's USD floor, if one is configured
+fn apply_usd_floor(
+    curve: &BondingCurve,
+    price_feed: &Option<Account<PriceFeed>>,
+    price: u64,
+) -> Result<u64> {
+    if curve.oracle == Pubkey::default() {
+        return Ok(price);
+    }
+
+    let feed = price_feed
+        .as_ref()
+        .ok_or(BondingCurveError::OracleRequired)?;
+    require_keys_eq!(feed.key(), curve.oracle, BondingCurveError::OracleRequired);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now.saturating_sub(feed.updated_at) <= curve.max_oracle_staleness,
+        BondingCurveError::OracleStale
+    );
+    require!(feed.usd_per_sol > 0, BondingCurveError::OracleStale);
+
+    // lamports = usd_floor / usd_per_sol * 1 SOL
+    let floor_lamports = (curve.usd_floor as u128)
+        .checked_mul(1_000_000_000)
+        .and_then(|v| v.checked_add(feed.usd_per_sol as u128 - 1))
+        .ok_or(BondingCurveError::ArithmeticOverflow)?
+        / feed.usd_per_sol as u128; // round up
+    let floor_lamports = u64::try_from(floor_lamports)
+        .map_err(|_| BondingCurveError::ArithmeticOverflow)?;
+
+    if floor_lamports > price {
+        msg!("Price raised from {} to USD floor of {} lamports", price, floor_lamports);
+        return Ok(floor_lamports);
+    }
+
+    Ok(price)
+}
+
+// Serial number for the nth edition sold (1-based), honoring reverse numbering
+fn edition_number(curve: &BondingCurve, position: u32) -> u32 {
+    if curve.reverse_numbering {
+        curve.max_supply - (position - 1)
+    } else {
+        position
+    }
+}
+
+// Gate shared by every public mint path that delivers straight to the buyer
+fn check_mintable(
+    curve: &mut BondingCurve,
+    next_reservation: &Option<UncheckedAccount>,
+    platform_config: &UncheckedAccount,
+) -> Result<()> {
+    ensure_public_sale(curve)?;
+    check_mint_gates(curve, next_reservation, platform_config)
+}
+
+// check_mintable for reserve_specific: the same routing, pause, sale-window,
+// refund-mode and period-cap gates; the reserved edition is validated by the caller
+fn check_reservable(curve: &mut BondingCurve, platform_config: &UncheckedAccount) -> Result<()> {
+    ensure_public_sale(curve)?;
+    ensure_sale_open(curve, platform_config)?;
+    consume_period_cap(curve)
+}
+
+// A reservation can be cancelled once it expires, or once the sale has ended or
+// turned to refunds before reaching it
+fn reservation_cancellable(curve: &BondingCurve, reservation: &EditionReservation, now: i64) -> bool {
+    let expired = reservation.expires_at > 0 && now > reservation.expires_at;
+    let closed = (curve.end_ts > 0 && now > curve.end_ts) || curve.refunding(now);
+    expired || closed
+}
+
+// Curves that deliver through the KYC escrow or an operator have no public sale
+fn ensure_public_sale(curve: &BondingCurve) -> Result<()> {
+    // Compliance-gated curves only deliver through the KYC escrow
+    require!(
+        curve.compliance_authority == Pubkey::default(),
+        BondingCurveError::EscrowRequired
+    );
+    // Operator-only curves mint through mint_edition_operator
+    require!(!curve.operator_only, BondingCurveError::OperatorRequired);
+
+    Ok(())
+}
+
+// Pause, sale-window and refund-mode checks
+fn ensure_sale_open(curve: &BondingCurve, platform_config: &UncheckedAccount) -> Result<()> {
+    ensure_not_globally_paused(platform_config)?;
+    require!(!curve.paused, BondingCurveError::CurvePaused);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= curve.start_ts, BondingCurveError::MintNotStarted);
+    require!(curve.end_ts == 0 || now <= curve.end_ts, BondingCurveError::MintEnded);
+    require!(!curve.refunding(now), BondingCurveError::RefundMode);
+
+    Ok(())
+}
+
+// Pause, supply, reservation and period-cap checks
+fn check_mint_gates(
+    curve: &mut BondingCurve,
+    next_reservation: &Option<UncheckedAccount>,
+    platform_config: &UncheckedAccount,
+) -> Result<()> {
+    ensure_sale_open(curve, platform_config)?;
+
+    // Check if max supply reached
+    require!(
+        curve.current_supply < curve.max_supply,
+        BondingCurveError::MaxSupplyReached
+    );
+
+    // Reserved editions can only be minted via claim_reserved
+    ensure_not_reserved(curve, next_reservation)?;
+
+    // Rolling per-period mint throttle
+    consume_period_cap(curve)
+}
+
+// Mint `editions` whole edition tokens, signed by the bonding curve PDA
+// Blind drops and soulbound curves freeze the destination
+fn mint_edition_token<'info>(
+    curve: &BondingCurve,
+    bonding_curve_info: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    edition_mint: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    editions: u32,
+) -> Result<()> {
+    let cpi_accounts = MintTo {
+        mint: edition_mint.clone(),
+        to: destination.clone(),
+        authority: bonding_curve_info.clone(),
+    };
+
+    let seeds = &[
+        b"bonding_curve",
+        curve.collection_mint.as_ref(),
+        &[curve.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // SPL Token or Token-2022, whichever program owns the edition mint
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer);
+
+    // Whole editions: 1 each for NFTs, 10^decimals each in semi-fungible mode
+    let amount = 10u64
+        .pow(curve.edition_decimals as u32)
+        .checked_mul(editions as u64)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    token_interface::mint_to(cpi_ctx, amount)?;
+
+    if curve.editions_locked() {
+        let cpi_accounts = FreezeAccount {
+            account: destination,
+            mint: edition_mint,
+            authority: bonding_curve_info,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+        token_interface::freeze_account(cpi_ctx)?;
+    }
+
+    Ok(())
+}
+
+// Record which curve minted an edition mint; reusing it under another curve fails
+// Batches share one receipt: `edition` is the last of `editions` and the amounts are totals
+#[allow(clippy::too_many_arguments)]
+// A refund returns one receipt's proceeds to the wallet that paid them, inside
+// the refund window or at any time once the curve is refunding
+fn check_refundable(
+    curve: &BondingCurve,
+    receipt: &MintReceipt,
+    holder: Pubkey,
+    now: i64,
+) -> Result<()> {
+    // Every fractional mint overwrites the one receipt for the edition mint
+    require!(curve.edition_decimals == 0, BondingCurveError::SharedReceipt);
+    require!(receipt.buyer == holder, BondingCurveError::Unauthorized);
+
+    if !curve.refunding(now) {
+        require!(curve.refund_window > 0, BondingCurveError::NotRefunding);
+        require!(
+            now.saturating_sub(receipt.minted_at) <= curve.refund_window,
+            BondingCurveError::RefundWindowClosed
+        );
+    }
+
+    Ok(())
+}
+
+// Unwind a refunded receipt from the curve and return the amount owed
+// Outside refund mode the platform keeps its fee; a failed crowdfund also hands
+// back the fee the vault accrued for the edition (token fees are paid out at mint)
+fn settle_refund(curve: &mut BondingCurve, receipt: &MintReceipt, now: i64) -> Result<u64> {
+    let mut refund = receipt.net_proceeds;
+    if curve.refunding(now) && curve.payment_mint.is_none() {
+        let fee = receipt.price_paid
+            .saturating_sub(receipt.net_proceeds)
+            .min(curve.accrued_platform_fees);
+        curve.accrued_platform_fees -= fee;
+        refund = refund
+            .checked_add(fee)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    }
+
+    // A batch receipt returns every edition it covers; reserve editions
+    // (edition 0) were never counted in supply
+    let counted = if receipt.edition == 0 { 0 } else { receipt.editions };
+    curve.current_supply = curve.current_supply
+        .checked_sub(counted)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    curve.total_volume = curve.total_volume.saturating_sub(receipt.price_paid);
+    curve.buyback_liability = curve.buyback_liability.saturating_sub(receipt.net_proceeds);
+
+    Ok(refund)
+}
+
+// Count one sold edition and the price paid for it
+fn record_sale(curve: &mut BondingCurve, price: u64) -> Result<()> {
+    curve.total_volume = curve.total_volume
+        .checked_add(price)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    curve.current_supply += 1;
+
+    Ok(())
+}
+
+// Sell-back payout for a receipt: bought back from the top of the curve, one
+// price per edition, but never more than the vault kept from the sale
+fn sellback_refund(curve: &BondingCurve, receipt: &MintReceipt) -> Result<u64> {
+    let mut refund = 0u64;
+    for sold in 0..receipt.editions {
+        refund = refund
+            .checked_add(calculate_price(curve, curve.current_supply - sold)?)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    }
+
+    Ok(refund.min(receipt.net_proceeds))
+}
+
+// Return a sold-back receipt's editions to the curve
+fn settle_sellback(curve: &mut BondingCurve, receipt: &MintReceipt, refund: u64) -> Result<()> {
+    curve.current_supply = curve.current_supply
+        .checked_sub(receipt.editions)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    curve.total_volume = curve.total_volume.saturating_sub(refund);
+    curve.buyback_liability = curve.buyback_liability.saturating_sub(receipt.net_proceeds);
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn bind_mint_receipt(
+    receipt: &mut MintReceipt,
+    curve: Pubkey,
+    edition_mint: Pubkey,
+    buyer: Pubkey,
+    edition: u32,
+    editions: u32,
+    price_paid: u64,
+    net_proceeds: u64,
+    bump: u8,
+) -> Result<()> {
+    require!(
+        receipt.bonding_curve == Pubkey::default() || receipt.bonding_curve == curve,
+        BondingCurveError::MintBoundToOtherCurve
+    );
+
+    receipt.bonding_curve = curve;
+    receipt.edition_mint = edition_mint;
+    receipt.buyer = buyer;
+    receipt.edition = edition;
+    receipt.editions = editions;
+    receipt.price_paid = price_paid;
+    receipt.net_proceeds = net_proceeds;
+    receipt.slot = Clock::get()?.slot;
+    receipt.bump = bump;
+    receipt.minted_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+// Premium for whole days elapsed since the curve was created (0 when disabled)
+fn time_premium(curve: &BondingCurve) -> Result<u64> {
+    if curve.time_premium_per_day == 0 {
+        return Ok(0);
+    }
+
+    let elapsed = Clock::get()?.unix_timestamp.saturating_sub(curve.created_at).max(0);
+    let days = (elapsed / 86_400) as u64;
+
+    days.checked_mul(curve.time_premium_per_day)
+        .ok_or_else(|| BondingCurveError::ArithmeticOverflow.into())
+}
+
+// Anti-bot spacing between a wallet's mints; its first mint is always allowed
+fn check_mint_cooldown(curve: &BondingCurve, counter: &MintCounter) -> Result<()> {
+    if curve.mint_cooldown_secs == 0 || counter.minted == 0 {
+        return Ok(());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now.saturating_sub(counter.last_mint_ts) >= curve.mint_cooldown_secs as i64,
+        BondingCurveError::CooldownActive
+    );
+
+    Ok(())
+}
+
+// Count a paid edition against the buyer's per-wallet record
+// A wallet's first mint also counts it as a unique holder, subject to `max_unique_minters`
+fn record_wallet_mint(
+    curve: &mut Account<BondingCurve>,
+    counter: &mut MintCounter,
+    buyer: Pubkey,
+    price_paid: u64,
+    bump: u8,
+) -> Result<()> {
+    if counter.minted == 0 {
+        require!(
+            curve.max_unique_minters == 0 || curve.unique_holders < curve.max_unique_minters,
+            BondingCurveError::MinterLimitReached
+        );
+        curve.unique_holders = curve.unique_holders
+            .checked_add(1)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    }
+
+    counter.bonding_curve = curve.key();
+    counter.buyer = buyer;
+    counter.minted = counter.minted
+        .checked_add(1)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    counter.total_paid = counter.total_paid
+        .checked_add(price_paid)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    counter.last_mint_ts = Clock::get()?.unix_timestamp;
+    counter.bump = bump;
+    curve.last_mint_at = counter.last_mint_ts;
+
+    Ok(())
+}
+
+// Emit NearSellout the first time minted supply crosses `near_sellout_bps`
+fn emit_near_sellout(curve: &mut Account<BondingCurve>) {
+    if curve.near_sellout_bps == 0 || curve.near_sellout_emitted || curve.max_supply == 0 {
+        return;
+    }
+
+    let progress_bps = curve.current_supply as u64 * 10000 / curve.max_supply as u64;
+    if progress_bps >= curve.near_sellout_bps as u64 {
+        curve.near_sellout_emitted = true;
+        emit!(NearSellout {
+            curve: curve.key(),
+            current_supply: curve.current_supply,
+            max_supply: curve.max_supply,
+        });
+    }
+}
+
+// Where a lamport mint payment goes, borrowed from the entry point's accounts
+struct Payees<'a, 'info> {
+    authority: &'a AccountInfo<'info>,
+    treasury: &'a Option<SystemAccount<'info>>,
+    incinerator: &'a Option<UncheckedAccount<'info>>,
+    platform_config: &'a UncheckedAccount<'info>,
+    platform_wallet: &'a Option<UncheckedAccount<'info>>,
+}
+
+// The edition side of a single mint: the token goes to `destination` (the
+// buyer's token account, or an escrow)
+struct Delivery<'a, 'info> {
+    edition_mint: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    mint_receipt: &'a mut MintReceipt,
+    receipt_bump: u8,
+    mint_counter: &'a mut MintCounter,
+    counter_bump: u8,
+}
+
+// Single-edition lamport mint, once the entry point has run its own checks and
+// priced the edition: slippage, payment from `payer` (less `prepaid`, already
+// sent elsewhere such as a referral reward), then delivery; returns the net proceeds
+#[allow(clippy::too_many_arguments)]
+fn do_mint<'info>(
+    curve: &mut Account<'info, BondingCurve>,
+    payer: &AccountInfo<'info>,
+    buyer: Pubkey,
+    payees: Payees<'_, 'info>,
+    delivery: Delivery<'_, 'info>,
+    price: u64,
+    prepaid: u64,
+    max_price: u64,
+) -> Result<u64> {
+    require!(price <= max_price, BondingCurveError::SlippageExceeded);
+
+    msg!("Minting edition #{} at {} lamports", edition_number(curve, curve.current_supply + 1), price);
+
+    // Platform fee off the top, then the rest to creator or treasury (less any burn share)
+    let net_proceeds = collect_payment(
+        curve,
+        payer,
+        payees.authority,
+        payees.treasury,
+        payees.incinerator,
+        payees.platform_config,
+        payees.platform_wallet,
+        &None,
+        &[],
+        price - prepaid,
+    )?;
+
+    deliver_edition(curve, buyer, delivery, price, net_proceeds)?;
+
+    Ok(net_proceeds)
+}
+
+// Everything after payment: receipt, token, curve state, the buyer's wallet record and the event
+fn deliver_edition<'info>(
+    curve: &mut Account<'info, BondingCurve>,
+    buyer: Pubkey,
+    delivery: Delivery<'_, 'info>,
+    price: u64,
+    net_proceeds: u64,
+) -> Result<()> {
+    // Tie the edition mint to this curve
+    bind_mint_receipt(
+        delivery.mint_receipt,
+        curve.key(),
+        delivery.edition_mint.key(),
+        buyer,
+        edition_number(curve, curve.current_supply + 1),
+        1,
+        price,
+        net_proceeds,
+        delivery.receipt_bump,
+    )?;
+
+    mint_edition_token(
+        curve,
+        curve.to_account_info(),
+        delivery.token_program,
+        delivery.edition_mint,
+        delivery.destination,
+        1,
+    )?;
+
+    // Volume records what was actually paid
+    record_sale(curve, price)?;
+    curve.buyback_liability = curve.buyback_liability
+        .checked_add(net_proceeds)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    emit_near_sellout(curve);
+
+    record_wallet_mint(curve, delivery.mint_counter, buyer, price, delivery.counter_bump)?;
+    emit!(EditionMinted {
+        curve: curve.key(),
+        edition_number: edition_number(curve, curve.current_supply),
+        price,
+        buyer,
+        new_supply: curve.current_supply,
+        unique_buyers: curve.unique_holders,
+    });
+
+    Ok(())
+}
+
+// The final edition must not price above `absolute_max_price` (0 = no cap)
+fn check_absolute_max_price(curve: &BondingCurve) -> Result<()> {
+    if curve.absolute_max_price == 0 || curve.max_supply == 0 {
+        return Ok(());
+    }
+
+    require!(
+        calculate_price(curve, curve.max_supply)? <= curve.absolute_max_price,
+        BondingCurveError::CurveExceedsMaxPrice
+    );
+
+    Ok(())
+}
+
+// Sum of curve prices for the next `quantity` editions
+fn batch_cost(curve: &BondingCurve, quantity: u32) -> Result<u64> {
+    let mut total = 0u64;
+    for edition in curve.current_supply + 1..=curve.current_supply + quantity {
+        total = total
+            .checked_add(calculate_price(curve, edition)?)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    }
+
+    Ok(total)
+}
+
+// Sum of curve prices for editions `first..=last` (0 for an empty range)
+// Linear, Constant, Step and DutchAuction are summed in closed form; other
+// curve types are priced edition by edition, at most MAX_PRICE_SUM_SPAN of them
+fn sum_prices(curve: &BondingCurve, first: u32, last: u32) -> Result<u64> {
+    if first > last {
+        return Ok(0);
+    }
+
+    // Past `flat_after` every edition costs the same as the first flat one
+    let flat = curve.flat_after.saturating_add(1);
+    let total = if curve.flat_after > 0 && last > flat {
+        let plateau = (last - first.max(flat + 1) + 1) as u128
+            * calculate_price(curve, flat)? as u128;
+        let climb = if first <= flat {
+            sum_climbing_prices(curve, first, flat)?
+        } else {
+            0
+        };
+        climb + plateau
+    } else {
+        sum_climbing_prices(curve, first, last)?
+    };
+
+    u64::try_from(total).map_err(|_| BondingCurveError::ArithmeticOverflow.into())
+}
+
+// Sum of prices for `first..=last`, none of them past the `flat_after` plateau
+fn sum_climbing_prices(curve: &BondingCurve, first: u32, last: u32) -> Result<u128> {
+    match curve.curve_type {
+        CurveType::Constant | CurveType::DutchAuction => affine_price_sum(curve, first, last),
+        CurveType::Linear => {
+            // Affine through the last edition before `steepen_at` kicks in, and again after it
+            let kink = curve.steepen_at as i64 + 1 - curve.supply_offset as i64;
+            if curve.steepen_at > 0 && kink >= first as i64 && kink < last as i64 {
+                let kink = kink as u32;
+                Ok(affine_price_sum(curve, first, kink)? + affine_price_sum(curve, kink + 1, last)?)
+            } else {
+                affine_price_sum(curve, first, last)
+            }
+        }
+        CurveType::Step => {
+            // Each price is a constant plus tier * increment, with tier = x / tier_size
+            // for x = shifted edition - 1; tiers_below(n) sums the tiers of x in 0..n
+            let tier_size = curve.tier_size.max(1) as u128;
+            let tiers_below = |n: u128| {
+                let (q, r) = (n / tier_size, n % tier_size);
+                tier_size * (q * q.saturating_sub(1) / 2) + q * r
+            };
+            let x_first = first as u128 + curve.supply_offset as u128 - 1;
+            let x_last = last as u128 + curve.supply_offset as u128 - 1;
+            let increment = curve.price_increment as u128;
+            let constant = calculate_price(curve, first)? as u128 - (x_first / tier_size) * increment;
+
+            // Surfaces the overflow a per-edition sum would hit on the last price
+            calculate_price(curve, last)?;
+
+            let count = (last - first + 1) as u128;
+            (tiers_below(x_last + 1) - tiers_below(x_first))
+                .checked_mul(increment)
+                .and_then(|steps| steps.checked_add(count * constant))
+                .ok_or(BondingCurveError::ArithmeticOverflow.into())
+        }
+        _ => {
+            require!(last - first < MAX_PRICE_SUM_SPAN, BondingCurveError::PriceSumTooLong);
+            let mut total = 0u128;
+            for edition in first..=last {
+                total += calculate_price(curve, edition)? as u128;
+            }
+            Ok(total)
+        }
+    }
+}
+
+// Most editions `budget` buys from the current supply, and what they cost
+// Binary-searches the closed-form sum; curves without one are walked edition
+// by edition and quote at most MAX_PRICE_SUM_SPAN editions
+fn budget_quote(curve: &BondingCurve, budget: u64) -> Result<(u32, u64)> {
+    let first = curve.current_supply + 1;
+    let remaining = curve.max_supply - curve.current_supply;
+
+    if !matches!(
+        curve.curve_type,
+        CurveType::Linear | CurveType::Constant | CurveType::Step | CurveType::DutchAuction
+    ) {
+        let mut editions = 0u32;
+        let mut total_cost = 0u64;
+        for edition in first..first + remaining.min(MAX_PRICE_SUM_SPAN) {
+            let next_total = total_cost
+                .checked_add(calculate_price(curve, edition)?)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?;
+            if next_total > budget {
+                break;
+            }
+            total_cost = next_total;
+            editions += 1;
+        }
+        return Ok((editions, total_cost));
+    }
+
+    // Cost grows with every edition, so the largest affordable count is a bisection away
+    let (mut low, mut high) = (0u32, remaining);
+    let mut total_cost = 0u64;
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+        match sum_prices(curve, first, first + mid - 1) {
+            Ok(cost) if cost <= budget => {
+                low = mid;
+                total_cost = cost;
+            }
+            _ => high = mid - 1,
+        }
+    }
+
+    Ok((low, total_cost))
+}
+
+// Sum of prices for `first..=last` over a stretch where the price is affine in the edition
+fn affine_price_sum(curve: &BondingCurve, first: u32, last: u32) -> Result<u128> {
+    let ends = calculate_price(curve, first)? as u128 + calculate_price(curve, last)? as u128;
+    Ok(ends * (last - first + 1) as u128 / 2)
+}
+
+// Wind-down preconditions for emptying the treasury vault: no crowdfund lock,
+// nothing left to back, no unwithdrawn platform fees and no open refund window
+fn ensure_vault_sweepable(curve: &BondingCurve) -> Result<()> {
+    require!(
+        !curve.crowdfund_locked(),
+        BondingCurveError::FundsLocked
+    );
+
+    require!(
+        (curve.sellback_disabled && curve.reserve_ratio == 0) || curve.current_supply == 0,
+        BondingCurveError::VaultStillBacking
+    );
+    // The platform's share must be withdrawn before the vault is emptied
+    require!(curve.accrued_platform_fees == 0, BondingCurveError::PlatformFeesOutstanding);
+    // Prepaid reservations are claimed or cancelled first
+    require!(curve.reserved_count == 0, BondingCurveError::VaultStillBacking);
+    require!(
+        !curve.refund_window_open(Clock::get()?.unix_timestamp),
+        BondingCurveError::FundsLocked
+    );
+
+    Ok(())
+}
+
+// Lamports the vault must keep: the full buy-back liability while sell-backs
+// are live, otherwise `reserve_ratio` bps of it (0 when neither applies)
+fn required_reserve(curve: &BondingCurve) -> Result<u64> {
+    let ratio_bps = if !curve.sellback_disabled && curve.sellback_enabled {
+        10000
+    } else {
+        curve.reserve_ratio as u128
+    };
+    if ratio_bps == 0 {
+        return Ok(0);
+    }
+
+    // Tracked as editions are minted and returned, so this stays O(1) in supply
+    let reserve = (curve.buyback_liability as u128)
+        .checked_mul(ratio_bps)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?
+        / 10000;
+
+    Ok(reserve as u64)
+}
+
+// Reject public mints of the next edition while it is reserved
+fn ensure_not_reserved(
+    curve: &BondingCurve,
+    next_reservation: &Option<UncheckedAccount>,
+) -> Result<()> {
+    if curve.reserved_count == 0 {
+        return Ok(());
+    }
+
+    let reservation = next_reservation
+        .as_ref()
+        .ok_or(BondingCurveError::ReservationAccountRequired)?;
+    require!(
+        reservation.data_is_empty(),
+        BondingCurveError::EditionReserved
+    );
+
+    Ok(())
+}
+
+// Lookup chunk and offset within it holding the price for 0-based `edition_idx`
+fn bezier_chunk_slot(edition_idx: u32) -> (u32, usize) {
+    (edition_idx / BEZIER_CHUNK_SIZE, (edition_idx % BEZIER_CHUNK_SIZE) as usize)
+}
+
+// `base^exp` for a `FIXED_POINT_SCALE`-scaled base, by repeated squaring
+fn fixed_pow(base: u128, mut exp: u32) -> Result<u128> {
+    let mut result = FIXED_POINT_SCALE;
+    let mut base = base;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result
+                .checked_mul(base)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+                / FIXED_POINT_SCALE;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = base
+                .checked_mul(base)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+                / FIXED_POINT_SCALE;
+        }
+    }
+
+    Ok(result)
+}
+
+// `log2(x)` scaled by `FIXED_POINT_SCALE`: bit length for the integer part,
+// then one fractional bit per squaring of the normalized mantissa
+fn fixed_log2(x: u32) -> u128 {
+    if x <= 1 {
+        return 0;
+    }
+
+    let int_part = 31 - x.leading_zeros();
+    // Mantissa in [1, 2) as a fixed-point value
+    let mut y = ((x as u128) * FIXED_POINT_SCALE) >> int_part;
+    let mut result = int_part as u128 * FIXED_POINT_SCALE;
+    let mut bit = FIXED_POINT_SCALE / 2;
+
+    while bit > 0 {
+        y = y * y / FIXED_POINT_SCALE;
+        if y >= 2 * FIXED_POINT_SCALE {
+            y /= 2;
+            result += bit;
+        }
+        bit /= 2;
+    }
+
+    result
+}
+
+// Fixed-point logistic approximation in [0, FIXED_POINT_SCALE] for the Sigmoid curve:
+// smoothstep `3t^2 - 2t^3` of a ramp centered on `max_supply / 2`. A `steepness`
+// of 10000 spreads the ramp over the whole supply; higher values narrow it, and
+// editions past the ramp sit exactly on the floor or ceiling.
+fn sigmoid_fraction(edition: u32, max_supply: u32, steepness: u64) -> u128 {
+    let scale = FIXED_POINT_SCALE as i128;
+
+    // t = 1/2 + (edition - max_supply/2) * steepness / (max_supply * 10000)
+    let offset = 2 * edition as i128 - max_supply as i128;
+    let num = offset * steepness as i128;
+    let denom = 2 * (max_supply.max(1) as i128) * 10000;
+    let t = if 2 * num >= denom {
+        scale
+    } else if -2 * num >= denom {
+        0
+    } else {
+        scale / 2 + num * scale / denom
+    };
+
+    let t_squared = t * t / scale;
+    (t_squared * (3 * scale - 2 * t) / scale) as u128
+}
+
+// Calculate price based on curve type and edition number
+fn calculate_price(
+    curve: &BondingCurve,
+    edition: u32,
+) -> Result<u64> {
+    let base_price = curve.base_price;
+    let price_increment = curve.price_increment;
+    let bezier_min_price = curve.bezier_min_price;
+    let bezier_max_price = curve.bezier_max_price;
+
+    // Graduated curves stop climbing once `flat_after` editions are sold
+    let edition = if curve.flat_after > 0 {
+        edition.min(curve.flat_after.saturating_add(1))
+    } else {
+        edition
+    };
+
+    // Shift the curve so edition 1 sits `supply_offset` editions up
+    let edition = edition
+        .checked_add(curve.supply_offset)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+    let max_supply = curve.max_supply
+        .checked_add(curve.supply_offset)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+    let price = match curve.curve_type {
+        CurveType::Linear => {
+            // price = base_price + (edition - 1) * increment
+            // Once `steepen_at` editions are sold, each further edition adds `post_steepen_increment`
+            let sold = edition as u64 - 1;
+            let steepen_at = curve.steepen_at as u64;
+            if steepen_at > 0 && sold > steepen_at {
+                let before = steepen_at
+                    .checked_mul(price_increment)
+                    .ok_or(BondingCurveError::ArithmeticOverflow)?;
+                let after = (sold - steepen_at)
+                    .checked_mul(curve.post_steepen_increment)
+                    .ok_or(BondingCurveError::ArithmeticOverflow)?;
+                base_price
+                    .checked_add(before)
+                    .and_then(|p| p.checked_add(after))
+                    .ok_or(BondingCurveError::ArithmeticOverflow)?
+            } else {
+                (edition as u64 - 1)
+                    .checked_mul(price_increment)
+                    .and_then(|step| base_price.checked_add(step))
+                    .ok_or(BondingCurveError::ArithmeticOverflow)?
+            }
         }
         CurveType::Exponential => {
-            // price = base_price * (1 + increment)^(edition - 1)
-            // Simplified: price = base_price + (base_price * increment * (edition - 1) / 10000)
-            let multiplier = price_increment.checked_mul(edition as u64 - 1).unwrap() / 10000;
-            base_price
-                .checked_add(base_price.checked_mul(multiplier).unwrap())
-                .unwrap()
+            // price = base_price * (1 + increment / 10000)^(edition - 1)
+            // Compounded in fixed point so each edition grows by `increment` bps
+            let growth = (price_increment as u128)
+                .checked_mul(FIXED_POINT_SCALE / 10000)
+                .and_then(|g| g.checked_add(FIXED_POINT_SCALE))
+                .ok_or(BondingCurveError::ArithmeticOverflow)?;
+            let factor = fixed_pow(growth, edition - 1)?;
+            let price = (base_price as u128)
+                .checked_mul(factor)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+                / FIXED_POINT_SCALE;
+            u64::try_from(price).map_err(|_| BondingCurveError::ArithmeticOverflow)?
         }
         CurveType::Logarithmic => {
             // price = base_price + increment * log2(edition)
-            // Approximation for on-chain
-            let log_edition = (edition as f64).log2() as u64;
-            base_price
-                .checked_add(price_increment.checked_mul(log_edition).unwrap())
-                .unwrap()
+            // log2 in integer fixed point so prices rise smoothly and deterministically
+            let step = (price_increment as u128)
+                .checked_mul(fixed_log2(edition))
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+                / FIXED_POINT_SCALE;
+            u64::try_from(step)
+                .ok()
+                .and_then(|step| base_price.checked_add(step))
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
         }
         CurveType::Bezier => {
             // For Bezier, we use the lookup table approach
@@ -287,176 +4680,2438 @@ fn calculate_price(
             
             // Simple interpolation between min and max based on supply progression
             // For more complex curves, use BezierPriceLookup account (see below)
-            let progress = (edition as u64 * 10000) / max_supply as u64; // 0-10000 (0-100%)
-            let price_range = bezier_max_price.checked_sub(bezier_min_price).unwrap();
-            let price_delta = price_range.checked_mul(progress).unwrap() / 10000;
+            // Edition 1 sits at min and edition max_supply at max (a single edition is priced at min)
+            let progress = if max_supply > 1 {
+                (edition as u64 - 1) * 10000 / (max_supply as u64 - 1) // 0-10000 (0-100%)
+            } else {
+                0
+            };
+            let price_range = bezier_max_price
+                .checked_sub(bezier_min_price)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?;
+            let price_delta = price_range
+                .checked_mul(progress)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+                / 10000;
             
-            bezier_min_price.checked_add(price_delta).unwrap()
+            bezier_min_price
+                .checked_add(price_delta)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+        }
+        CurveType::Constant => {
+            // Flat pricing: every edition costs base_price
+            base_price
+        }
+        CurveType::Sigmoid => {
+            // S-curve from base_price (floor) to bezier_max_price (ceiling),
+            // inflecting at max_supply / 2; price_increment is the steepness
+            let price_range = bezier_max_price
+                .checked_sub(base_price)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?;
+            let step = (price_range as u128)
+                .checked_mul(sigmoid_fraction(edition, max_supply, price_increment))
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+                / FIXED_POINT_SCALE;
+            base_price
+                .checked_add(step as u64)
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
+        }
+        CurveType::DutchAuction => {
+            // price = base_price - increment * seconds since the auction opened,
+            // never below bezier_min_price; the same for every edition
+            let opened_at = curve.created_at.max(curve.start_ts);
+            let elapsed = Clock::get()?.unix_timestamp.saturating_sub(opened_at).max(0) as u64;
+            let decay = price_increment.saturating_mul(elapsed);
+            base_price.saturating_sub(decay).max(bezier_min_price)
+        }
+        CurveType::Step => {
+            // price = base_price + ((edition - 1) / tier_size) * increment
+            // Flat within a tier, jumping by `increment` at each tier boundary
+            let tier = ((edition - 1) / curve.tier_size.max(1)) as u64;
+            tier.checked_mul(price_increment)
+                .and_then(|step| base_price.checked_add(step))
+                .ok_or(BondingCurveError::ArithmeticOverflow)?
         }
     };
 
-    Ok(price)
+    // Late-comer premium on top of the supply-based price
+    let price = price
+        .checked_add(time_premium(curve)?)
+        .ok_or(BondingCurveError::ArithmeticOverflow)?;
+
+    Ok(price)
+}
+
+#[derive(Accounts)]
+pub struct InitializeCurve<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BondingCurve::INIT_SPACE,
+        seeds = [b"bonding_curve", collection_mint.key().as_ref()],
+        bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    pub collection_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct MintEdition<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    /// Per-wallet mint count and lamports paid for this curve
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintCounter::INIT_SPACE,
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = edition_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// CHECK: Authority receives payment
+    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
+    pub authority_account: AccountInfo<'info>,
+    
+    /// Treasury vault PDA (required when proceeds are held by the curve)
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+    
+    /// CHECK: Burn address for the deflationary fee (required when burn_bps > 0)
+    #[account(mut, address = INCINERATOR_ID)]
+    pub incinerator: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Platform config PDA; no fee or global pause while it does not exist
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the platform fee (required while the fee is non-zero); checked against the config
+    #[account(mut)]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+    
+    /// Buyer's discount coupon, consumed on use
+    #[account(
+        mut,
+        seeds = [b"coupon", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump = coupon.bump
+    )]
+    pub coupon: Option<Account<'info, Coupon>>,
+    
+    /// Royalty split (required once one is configured); recipients follow as remaining accounts
+    #[account(
+        seeds = [b"royalty_split", bonding_curve.key().as_ref()],
+        bump = royalty_split.bump
+    )]
+    pub royalty_split: Option<Account<'info, RoyaltySplit>>,
+    
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    /// Payment token (required when the curve is priced in an SPL token)
+    #[account(mut)]
+    pub payment_mint: Option<Account<'info, Mint>>,
+    
+    /// Buyer's payment token account (required for SPL payment)
+    #[account(mut)]
+    pub buyer_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Creator's payment token account receiving SPL payments
+    #[account(mut)]
+    pub authority_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Treasury vault's associated token account for the payment mint
+    /// (required for SPL payment when proceeds are held by the curve)
+    #[account(mut)]
+    pub treasury_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Platform wallet's payment token account (required for SPL payment while the fee is non-zero)
+    #[account(mut)]
+    pub platform_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Reservation PDA for the next edition; must be empty (required once editions are reserved)
+    #[account(
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &(bonding_curve.current_supply + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_reservation: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Accounts shared by the lamport mint paths where the buyer signs, pays and
+/// receives the edition
+#[derive(Accounts)]
+pub struct EditionPurchase<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Platform config PDA; mints are blocked while it is globally paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the platform fee (required while the fee is non-zero); checked against the config
+    #[account(mut)]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    /// Per-wallet mint count and lamports paid for this curve
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintCounter::INIT_SPACE,
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = edition_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// CHECK: Authority receives payment
+    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
+    pub authority_account: AccountInfo<'info>,
+    
+    /// Treasury vault PDA (required when proceeds are held by the curve)
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+    
+    /// CHECK: Burn address for the deflationary fee (required when burn_bps > 0)
+    #[account(mut, address = INCINERATOR_ID)]
+    pub incinerator: Option<UncheckedAccount<'info>>,
+    
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    /// CHECK: Reservation PDA for the next edition; must be empty (required once editions are reserved)
+    #[account(
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &(bonding_curve.current_supply + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_reservation: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EditionPurchase<'info> {
+    // do_mint for the signing buyer, who pays and receives the edition
+    fn mint(&mut self, bumps: &EditionPurchaseBumps, price: u64, prepaid: u64, max_price: u64) -> Result<u64> {
+        let payees = Payees {
+            authority: &self.authority_account,
+            treasury: &self.treasury,
+            incinerator: &self.incinerator,
+            platform_config: &self.platform_config,
+            platform_wallet: &self.platform_wallet,
+        };
+        let delivery = Delivery {
+            edition_mint: self.edition_mint.to_account_info(),
+            destination: self.buyer_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            mint_receipt: &mut self.mint_receipt,
+            receipt_bump: bumps.mint_receipt,
+            mint_counter: &mut self.mint_counter,
+            counter_bump: bumps.mint_counter,
+        };
+
+        do_mint(
+            &mut self.bonding_curve,
+            &self.buyer.to_account_info(),
+            self.buyer.key(),
+            payees,
+            delivery,
+            price,
+            prepaid,
+            max_price,
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct MintEditionBatch<'info> {
+    pub purchase: EditionPurchase<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(referrer: Pubkey)]
+pub struct MintEditionWithReferral<'info> {
+    pub purchase: EditionPurchase<'info>,
+    
+    /// Receives the referral reward
+    #[account(mut, address = referrer)]
+    pub referrer_account: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintEditionOperator<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Platform config PDA; mints are blocked while it is globally paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the platform fee (required while the fee is non-zero); checked against the config
+    #[account(mut)]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    /// Per-wallet mint count and lamports paid for this curve
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = 8 + MintCounter::INIT_SPACE,
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
+    
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = edition_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    /// Pays the mint price and receives the edition
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// Hot wallet that triggers the mint and pays rent; never receives funds
+    #[account(mut, constraint = operator.key() == bonding_curve.operator @ BondingCurveError::Unauthorized)]
+    pub operator: Signer<'info>,
+    
+    /// CHECK: Authority receives payment
+    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
+    pub authority_account: AccountInfo<'info>,
+    
+    /// Treasury vault PDA (required when proceeds are held by the curve)
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+    
+    /// CHECK: Burn address for the deflationary fee (required when burn_bps > 0)
+    #[account(mut, address = INCINERATOR_ID)]
+    pub incinerator: Option<UncheckedAccount<'info>>,
+    
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    /// CHECK: Reservation PDA for the next edition; must be empty (required once editions are reserved)
+    #[account(
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &(bonding_curve.current_supply + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_reservation: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct MintEditionReserved<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Platform config PDA; mints are blocked while it is globally paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the platform fee (required while the fee is non-zero); checked against the config
+    #[account(mut)]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+    
+    /// Buyer's held price, consumed by this mint
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"price_reservation", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump = price_reservation.bump,
+        has_one = bonding_curve,
+        has_one = buyer
+    )]
+    pub price_reservation: Account<'info, PriceReservation>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    /// Per-wallet mint count and lamports paid for this curve
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintCounter::INIT_SPACE,
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = edition_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// CHECK: Authority receives payment
+    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
+    pub authority_account: AccountInfo<'info>,
+    
+    /// Treasury vault PDA (required when proceeds are held by the curve)
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+    
+    /// CHECK: Burn address for the deflationary fee (required when burn_bps > 0)
+    #[account(mut, address = INCINERATOR_ID)]
+    pub incinerator: Option<UncheckedAccount<'info>>,
+    
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    /// CHECK: Reservation PDA for the next edition; must be empty (required once editions are reserved)
+    #[account(
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &(bonding_curve.current_supply + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_reservation: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ReservePrice<'info> {
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + PriceReservation::INIT_SPACE,
+        seeds = [b"price_reservation", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub price_reservation: Account<'info, PriceReservation>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintEditionHooked<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Platform config PDA; mints are blocked while it is globally paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the platform fee (required while the fee is non-zero); checked against the config
+    #[account(mut)]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+    
+    /// Fresh Token-2022 edition mint; the curve PDA is mint, freeze and hook authority
+    #[account(
+        init,
+        payer = buyer,
+        mint::decimals = bonding_curve.edition_decimals,
+        mint::authority = bonding_curve,
+        mint::freeze_authority = bonding_curve,
+        mint::token_program = token_program,
+        extensions::transfer_hook::authority = bonding_curve,
+        extensions::transfer_hook::program_id = bonding_curve.transfer_hook_program,
+    )]
+    pub edition_mint: InterfaceAccount<'info, token_interface::Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    /// Per-wallet mint count and lamports paid for this curve
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintCounter::INIT_SPACE,
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        associated_token::mint = edition_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program
+    )]
+    pub buyer_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// CHECK: Authority receives payment
+    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
+    pub authority_account: AccountInfo<'info>,
+    
+    /// Treasury vault PDA (required when proceeds are held by the curve)
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+    
+    /// CHECK: Burn address for the deflationary fee (required when burn_bps > 0)
+    #[account(mut, address = INCINERATOR_ID)]
+    pub incinerator: Option<UncheckedAccount<'info>>,
+    
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    /// CHECK: Reservation PDA for the next edition; must be empty (required once editions are reserved)
+    #[account(
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &(bonding_curve.current_supply + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_reservation: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCurve<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Governance sign-off (required to raise max_supply once governance is set)
+    #[account(
+        seeds = [b"governance_approval", bonding_curve.key().as_ref()],
+        bump = governance_approval.bump
+    )]
+    pub governance_approval: Option<Account<'info, GovernanceApproval>>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.pending_authority == Some(new_authority.key()) @ BondingCurveError::NotPendingAuthority
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RecordSecondaryVolume<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.volume_reporter == reporter.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    pub reporter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ThawEdition<'info> {
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// SPL Token or Token-2022 (transfer-hook) edition mint
+    #[account(mint::token_program = token_program)]
+    pub edition_mint: InterfaceAccount<'info, token_interface::Mint>,
+    
+    #[account(mut, token::mint = edition_mint, token::token_program = token_program)]
+    pub token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveSupplyIncrease<'info> {
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.governance == governance.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        init_if_needed,
+        payer = governance,
+        space = 8 + GovernanceApproval::INIT_SPACE,
+        seeds = [b"governance_approval", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub governance_approval: Account<'info, GovernanceApproval>,
+    
+    #[account(mut)]
+    pub governance: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseCurve<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Teardown<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Treasury vault PDA holding curve-custodied lamports
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    /// Bezier lookup table, if one was created
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"bezier_lookup", bonding_curve.key().as_ref()],
+        bump = bezier_lookup.bump
+    )]
+    pub bezier_lookup: Option<Account<'info, BezierPriceLookup>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SellEdition<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(mut)]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Proves the edition was minted by this curve; closed on sale
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump = mint_receipt.bump,
+        constraint = mint_receipt.bonding_curve == bonding_curve.key() @ BondingCurveError::MintBoundToOtherCurve
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    #[account(mut, token::mint = edition_mint, token::authority = seller)]
+    pub seller_token_account: Account<'info, TokenAccount>,
+    
+    /// Treasury vault PDA holding curve-custodied lamports
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    /// Treasury vault's associated token account for the payment mint (token-priced curves)
+    #[account(mut)]
+    pub treasury_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Seller's payment token account receiving the refund (token-priced curves)
+    #[account(mut)]
+    pub seller_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefundEdition<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(mut)]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// What was paid for the edition; closed so it cannot be refunded twice
+    #[account(
+        mut,
+        close = holder,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump = mint_receipt.bump,
+        constraint = mint_receipt.bonding_curve == bonding_curve.key() @ BondingCurveError::MintBoundToOtherCurve
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    #[account(mut, token::mint = edition_mint, token::authority = holder)]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// Treasury vault PDA holding curve-custodied lamports
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    /// Treasury vault's associated token account for the payment mint (token-priced curves)
+    #[account(mut)]
+    pub treasury_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Holder's payment token account receiving the refund (token-priced curves)
+    #[account(mut)]
+    pub holder_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BackfillVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Treasury vault PDA holding curve-custodied lamports
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SweepVault<'info> {
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Treasury vault PDA holding curve-custodied lamports
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    /// Treasury vault's associated token account for the payment mint (token-priced curves)
+    #[account(mut)]
+    pub treasury_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Creator's payment token account receiving withdrawals (token-priced curves)
+    #[account(mut)]
+    pub authority_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = admin @ BondingCurveError::Unauthorized,
+        has_one = platform_wallet @ BondingCurveError::PlatformWalletRequired
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Treasury vault PDA holding the accrued fees
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    /// CHECK: Platform fee recipient from the config
+    #[account(mut)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeBezierLookup<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 4 + (8 * 1000) + 1, // Max 1000 prices
+        seeds = [b"bezier_lookup", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub bezier_lookup: Account<'info, BezierPriceLookup>,
+    
+    #[account(
+        constraint = bonding_curve.curve_type == CurveType::Bezier @ BondingCurveError::InvalidCurveType,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(chunk_index: u32)]
+pub struct InitializeBezierChunk<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BezierLookupChunk::INIT_SPACE,
+        seeds = [b"bezier_chunk", bonding_curve.key().as_ref(), &chunk_index.to_le_bytes()],
+        bump
+    )]
+    pub bezier_chunk: Account<'info, BezierLookupChunk>,
+    
+    /// CHECK: Single Bezier lookup PDA; must not exist on a chunked curve
+    #[account(
+        seeds = [b"bezier_lookup", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub bezier_lookup: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = bonding_curve.curve_type == CurveType::Bezier @ BondingCurveError::InvalidCurveType,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateToBezier<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 4 + (8 * 1000) + 1, // Max 1000 prices
+        seeds = [b"bezier_lookup", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub bezier_lookup: Account<'info, BezierPriceLookup>,
+    
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateBezierLookup<'info> {
+    #[account(
+        mut,
+        seeds = [b"bezier_lookup", bonding_curve.key().as_ref()],
+        bump = bezier_lookup.bump
+    )]
+    pub bezier_lookup: Account<'info, BezierPriceLookup>,
+    
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintEditionWithBezierLookup<'info> {
+    pub purchase: EditionPurchase<'info>,
+    
+    /// Single lookup table (collections up to 1000 editions)
+    #[account(
+        seeds = [b"bezier_lookup", purchase.bonding_curve.key().as_ref()],
+        bump = bezier_lookup.bump
+    )]
+    pub bezier_lookup: Option<Account<'info, BezierPriceLookup>>,
+    
+    /// Lookup chunk covering the next edition (paginated lookups)
+    #[account(
+        seeds = [
+            b"bezier_chunk",
+            purchase.bonding_curve.key().as_ref(),
+            &(purchase.bonding_curve.current_supply / BEZIER_CHUNK_SIZE).to_le_bytes()
+        ],
+        bump = bezier_chunk.bump
+    )]
+    pub bezier_chunk: Option<Account<'info, BezierLookupChunk>>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePriceFeed<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PriceFeed::INIT_SPACE,
+        seeds = [b"price_feed", authority.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRoyaltySplit<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoyaltySplit::INIT_SPACE,
+        seeds = [b"royalty_split", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub royalty_split: Account<'info, RoyaltySplit>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(buyer: Pubkey)]
+pub struct IssueCoupon<'info> {
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Coupon::INIT_SPACE,
+        seeds = [b"coupon", bonding_curve.key().as_ref(), buyer.as_ref()],
+        bump
+    )]
+    pub coupon: Account<'info, Coupon>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpsertCreatorProfile<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CreatorProfile::INIT_SPACE,
+        seeds = [b"creator_profile", authority.key().as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LinkCreatorProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        seeds = [b"creator_profile", authority.key().as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SyncCreatorProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.creator_profile == creator_profile.key() @ BondingCurveError::CreatorProfileMismatch
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// The linked profile; must still belong to the curve's authority
+    #[account(
+        seeds = [b"creator_profile", bonding_curve.authority.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PlatformConfig::INIT_SPACE,
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = admin @ BondingCurveError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePriceFeed<'info> {
+    #[account(
+        mut,
+        seeds = [b"price_feed", authority.key().as_ref()],
+        bump = price_feed.bump,
+        has_one = authority @ BondingCurveError::Unauthorized
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintTeamAllocation<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = edition_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Receives the team editions
+    pub recipient: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintReserve<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = edition_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Receives the reserve edition
+    pub recipient: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(edition: u32)]
+pub struct ReserveSpecific<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Platform config PDA; mints are blocked while it is globally paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the platform fee (required while the fee is non-zero); checked against the config
+    #[account(mut)]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + EditionReservation::INIT_SPACE,
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &edition.to_le_bytes()],
+        bump
+    )]
+    pub reservation: Account<'info, EditionReservation>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// CHECK: Authority receives payment
+    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
+    pub authority_account: AccountInfo<'info>,
+    
+    /// Treasury vault PDA (required when proceeds are held by the curve)
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+    
+    /// CHECK: Burn address for the deflationary fee (required when burn_bps > 0)
+    #[account(mut, address = INCINERATOR_ID)]
+    pub incinerator: Option<UncheckedAccount<'info>>,
+    
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelReservation<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &reservation.edition.to_le_bytes()],
+        bump = reservation.bump,
+        has_one = bonding_curve,
+        has_one = buyer
+    )]
+    pub reservation: Account<'info, EditionReservation>,
+    
+    /// Treasury vault PDA holding the reservation's payment
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeCurve<'info> {
+pub struct ClaimReserved<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Platform config PDA; mints are blocked while it is globally paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &reservation.edition.to_le_bytes()],
+        bump = reservation.bump,
+        has_one = bonding_curve,
+        has_one = buyer
+    )]
+    pub reservation: Account<'info, EditionReservation>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    /// Per-wallet mint count and lamports paid for this curve
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MintCounter::INIT_SPACE,
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = edition_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Reservation holder; receives the edition and reservation rent
+    #[account(mut)]
+    pub buyer: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(max_price: u64, expires_at: i64, nonce: u64)]
+pub struct MintWithPermit<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Platform config PDA; mints are blocked while it is globally paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the platform fee (required while the fee is non-zero); checked against the config
+    #[account(mut)]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+    
+    /// Marks the permit as used so it cannot be replayed
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + UsedPermit::INIT_SPACE,
+        seeds = [b"permit", bonding_curve.key().as_ref(), buyer.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    pub permit: Account<'info, UsedPermit>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    /// Per-wallet mint count and lamports paid for this curve
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + MintCounter::INIT_SPACE,
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
+    
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        associated_token::mint = edition_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Permit signer; authorization is proven by the ed25519 instruction
+    pub buyer: AccountInfo<'info>,
+    
+    /// Buyer-funded lamports the price is paid from
+    #[account(
+        mut,
+        seeds = [b"permit_deposit", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump = permit_deposit.bump
+    )]
+    pub permit_deposit: Account<'info, PermitDeposit>,
+    
+    /// Submits the transaction, forwards the price from the deposit and pays rent
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    
+    /// CHECK: Authority receives payment
+    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
+    pub authority_account: AccountInfo<'info>,
+    
+    /// Treasury vault PDA (required when proceeds are held by the curve)
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+    
+    /// CHECK: Burn address for the deflationary fee (required when burn_bps > 0)
+    #[account(mut, address = INCINERATOR_ID)]
+    pub incinerator: Option<UncheckedAccount<'info>>,
+    
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    /// CHECK: Reservation PDA for the next edition; must be empty (required once editions are reserved)
+    #[account(
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &(bonding_curve.current_supply + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_reservation: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Instructions sysvar, used to inspect the ed25519 verification
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundPermitDeposit<'info> {
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + PermitDeposit::INIT_SPACE,
+        seeds = [b"permit_deposit", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub permit_deposit: Account<'info, PermitDeposit>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawPermitDeposit<'info> {
+    #[account(
+        mut,
+        seeds = [b"permit_deposit", permit_deposit.bonding_curve.as_ref(), buyer.key().as_ref()],
+        bump = permit_deposit.bump,
+        has_one = buyer
+    )]
+    pub permit_deposit: Account<'info, PermitDeposit>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintEditionAllowlisted<'info> {
+    pub purchase: EditionPurchase<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintEditionDualPay<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Platform config PDA; mints are blocked while it is globally paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    /// Per-wallet mint count and lamports paid for this curve
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintCounter::INIT_SPACE,
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
+    
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = edition_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    /// Token the curve is priced in
+    #[account(
+        mut,
+        constraint = bonding_curve.payment_mint == Some(payment_mint.key()) @ BondingCurveError::PaymentMintMismatch
+    )]
+    pub payment_mint: Account<'info, Mint>,
+    
+    #[account(mut, token::mint = payment_mint, token::authority = buyer)]
+    pub buyer_payment_account: Account<'info, TokenAccount>,
+    
+    /// Creator's account receiving the token payment (required unless proceeds are held by the curve)
+    #[account(mut)]
+    pub authority_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Treasury vault PDA (required when proceeds are held by the curve)
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+    
+    /// Treasury vault's associated token account for the payment mint
+    /// (required when proceeds are held by the curve)
+    #[account(mut)]
+    pub treasury_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Platform wallet's payment token account (required while the fee is non-zero)
+    #[account(mut)]
+    pub platform_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    /// CHECK: Receives the flat lamport fee
+    #[account(mut, address = bonding_curve.fee_recipient)]
+    pub fee_recipient: AccountInfo<'info>,
+    
+    /// CHECK: Reservation PDA for the next edition; must be empty (required once editions are reserved)
+    #[account(
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &(bonding_curve.current_supply + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_reservation: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintEditionEscrowed<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Platform config PDA; mints are blocked while it is globally paused
+    #[account(
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the platform fee (required while the fee is non-zero); checked against the config
+    #[account(mut)]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        constraint = edition_mint.decimals == bonding_curve.edition_decimals @ BondingCurveError::InvalidMintDecimals,
+        // A 1/1 edition mint must be fresh; semi-fungible editions share a mint
+        constraint = edition_mint.supply == 0 || bonding_curve.edition_decimals > 0 @ BondingCurveError::InvalidEditionMint
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// Binds the edition mint to the curve that minted it
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintReceipt::INIT_SPACE,
+        seeds = [b"mint_receipt", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
+    
+    /// Per-wallet mint count and lamports paid for this curve
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + MintCounter::INIT_SPACE,
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
+    
+    /// Who the escrowed edition belongs to
     #[account(
         init,
-        payer = authority,
-        space = 8 + BondingCurve::INIT_SPACE,
-        seeds = [b"bonding_curve", collection_mint.key().as_ref()],
+        payer = buyer,
+        space = 8 + EditionEscrow::INIT_SPACE,
+        seeds = [b"edition_escrow", edition_mint.key().as_ref()],
         bump
     )]
-    pub bonding_curve: Account<'info, BondingCurve>,
+    pub edition_escrow: Account<'info, EditionEscrow>,
     
-    pub collection_mint: Account<'info, Mint>,
+    /// Curve-owned token account holding the edition until release
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"escrow_tokens", edition_mint.key().as_ref()],
+        bump,
+        token::mint = edition_mint,
+        token::authority = bonding_curve
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
     
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub buyer: Signer<'info>,
+    
+    /// CHECK: Authority receives payment
+    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
+    pub authority_account: AccountInfo<'info>,
     
+    /// Treasury vault PDA (required when proceeds are held by the curve)
+    #[account(
+        mut,
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<SystemAccount<'info>>,
+    
+    /// CHECK: Burn address for the deflationary fee (required when burn_bps > 0)
+    #[account(mut, address = INCINERATOR_ID)]
+    pub incinerator: Option<UncheckedAccount<'info>>,
+    
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    /// CHECK: Reservation PDA for the next edition; must be empty (required once editions are reserved)
+    #[account(
+        seeds = [b"reservation", bonding_curve.key().as_ref(), &(bonding_curve.current_supply + 1).to_le_bytes()],
+        bump
+    )]
+    pub next_reservation: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct MintEdition<'info> {
+#[instruction(buyer: Pubkey)]
+pub struct ReleaseEdition<'info> {
     #[account(
         mut,
         seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
-        bump = bonding_curve.bump
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.compliance_authority == compliance_authority.key() @ BondingCurveError::Unauthorized
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
     
-    #[account(mut)]
     pub edition_mint: Account<'info, Mint>,
     
+    #[account(
+        mut,
+        close = buyer_account,
+        seeds = [b"edition_escrow", edition_mint.key().as_ref()],
+        bump = edition_escrow.bump,
+        constraint = edition_escrow.bonding_curve == bonding_curve.key() @ BondingCurveError::MintBoundToOtherCurve,
+        constraint = edition_escrow.buyer == buyer @ BondingCurveError::Unauthorized
+    )]
+    pub edition_escrow: Account<'info, EditionEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_tokens", edition_mint.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    
     #[account(
         init_if_needed,
-        payer = buyer,
+        payer = compliance_authority,
         associated_token::mint = edition_mint,
-        associated_token::authority = buyer
+        associated_token::authority = buyer_account
     )]
     pub buyer_token_account: Account<'info, TokenAccount>,
     
-    #[account(mut)]
-    pub buyer: Signer<'info>,
+    /// CHECK: The escrowed edition's buyer; receives the escrow rent
+    #[account(mut, address = buyer)]
+    pub buyer_account: AccountInfo<'info>,
     
-    /// CHECK: Authority receives payment
-    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
-    pub authority_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub compliance_authority: Signer<'info>,
     
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateCurve<'info> {
+pub struct GetLookupInfo<'info> {
     #[account(
-        mut,
         seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
-        bump = bonding_curve.bump,
-        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+        bump = bonding_curve.bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
     
-    pub authority: Signer<'info>,
+    /// CHECK: Bezier lookup PDA; may not exist yet
+    #[account(
+        seeds = [b"bezier_lookup", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub bezier_lookup: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
-pub struct CloseCurve<'info> {
+#[instruction(buyer: Pubkey)]
+pub struct ViewHolder<'info> {
     #[account(
-        mut,
-        close = authority,
         seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
-        bump = bonding_curve.bump,
-        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+        bump = bonding_curve.bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"mint_counter", bonding_curve.key().as_ref(), buyer.as_ref()],
+        bump = mint_counter.bump
+    )]
+    pub mint_counter: Account<'info, MintCounter>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeBezierLookup<'info> {
+pub struct ViewVault<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + 32 + 4 + (8 * 1000) + 1, // Max 1000 prices
-        seeds = [b"bezier_lookup", bonding_curve.key().as_ref()],
-        bump
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
     )]
-    pub bezier_lookup: Account<'info, BezierPriceLookup>,
+    pub bonding_curve: Account<'info, BondingCurve>,
     
+    /// Treasury vault PDA holding curve-custodied lamports
     #[account(
-        constraint = bonding_curve.curve_type == CurveType::Bezier @ BondingCurveError::InvalidCurveType,
-        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+        seeds = [b"treasury", bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(edition_mint: Pubkey)]
+pub struct ViewEdition<'info> {
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+    #[account(
+        seeds = [b"mint_receipt", edition_mint.as_ref()],
+        bump = mint_receipt.bump,
+        constraint = mint_receipt.bonding_curve == bonding_curve.key() @ BondingCurveError::MintBoundToOtherCurve
+    )]
+    pub mint_receipt: Account<'info, MintReceipt>,
 }
 
 #[derive(Accounts)]
-pub struct MintEditionWithBezierLookup<'info> {
+pub struct ViewCurve<'info> {
     #[account(
-        mut,
         seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
         bump = bonding_curve.bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
-    
+}
+
+#[derive(Accounts)]
+pub struct ViewFeeBreakdown<'info> {
     #[account(
-        seeds = [b"bezier_lookup", bonding_curve.key().as_ref()],
-        bump = bezier_lookup.bump
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
     )]
-    pub bezier_lookup: Account<'info, BezierPriceLookup>,
-    
-    #[account(mut)]
-    pub edition_mint: Account<'info, Mint>,
-    
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    /// CHECK: Platform config PDA; may not exist yet
     #[account(
-        init_if_needed,
-        payer = buyer,
-        associated_token::mint = edition_mint,
-        associated_token::authority = buyer
+        seeds = [b"platform_config"],
+        bump
     )]
-    pub buyer_token_account: Account<'info, TokenAccount>,
+    pub platform_config: UncheckedAccount<'info>,
     
-    #[account(mut)]
-    pub buyer: Signer<'info>,
-    
-    /// CHECK: Authority receives payment
-    #[account(mut, constraint = authority_account.key() == bonding_curve.authority)]
-    pub authority_account: AccountInfo<'info>,
+    /// Buyer's coupon to quote a discounted mint_edition price with
+    #[account(constraint = coupon.bonding_curve == bonding_curve.key() @ BondingCurveError::MintBoundToOtherCurve)]
+    pub coupon: Option<Account<'info, Coupon>>,
     
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+    /// USD price feed (required when a USD floor is configured)
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BondingCurve {
+    pub authority: Pubkey,          // 32
+    pub collection_mint: Pubkey,    // 32
+    pub curve_type: CurveType,      // 1
+    pub base_price: u64,            // 8
+    pub price_increment: u64,       // 8
+    pub max_supply: u32,            // 4
+    pub current_supply: u32,        // 4
+    pub total_volume: u64,          // 8
+    pub bump: u8,                   // 1
+    // Bezier curve: min and max prices
+    pub bezier_min_price: u64,      // 8
+    pub bezier_max_price: u64,      // 8
+    // Editions treated as already sold when pricing
+    pub supply_offset: u32,         // 4
+    // Linear steepening milestone (0 = disabled)
+    pub steepen_at: u32,            // 4
+    pub post_steepen_increment: u64, // 8
+    // Editions reserved via reserve_specific but not yet claimed
+    pub reserved_count: u32,        // 4
+    pub highest_reserved: u32,      // 4
+    // Share of each payment burned (basis points) and running total
+    pub burn_bps: u16,              // 2
+    pub total_burned: u64,          // 8
+    // Team editions minted outside the public supply
+    pub reserved_minted: u32,       // 4
+    // Once set, holders can no longer sell editions back (never cleared)
+    pub sellback_disabled: bool,    // 1
+    // Temporary halt of sell-backs only; minting is unaffected
+    pub sellback_paused: bool,      // 1
+    // Serials count down from max_supply instead of up from 1
+    pub reverse_numbering: bool,    // 1
+    // USD floor via price feed (oracle = default disables)
+    pub oracle: Pubkey,             // 32
+    pub usd_floor: u64,             // 8 (micro-USD)
+    pub max_oracle_staleness: i64,  // 8 (seconds)
+    // Emergency stop for minting, with the reason for observability
+    pub paused: bool,               // 1
+    pub pause_reason: PauseReason,  // 1
+    // Per-period mint throttle (period_secs = 0 disables)
+    pub period_secs: i64,           // 8
+    pub period_cap: u32,            // 4
+    pub period_start: i64,          // 8
+    pub period_minted: u32,         // 4
+    // Sell-back support backed by the treasury vault
+    pub sellback_enabled: bool,     // 1
+    pub backfilled_reserve: u64,    // 8
+    // Proposed new authority awaiting acceptance
+    pub pending_authority: Option<Pubkey>, // 1 + 32
+    // Merkle root of (wallet, max_qty) allowlist leaves
+    pub merkle_root: Option<[u8; 32]>, // 1 + 32
+    // Address that must approve max_supply increases (default = authority alone)
+    pub governance: Pubkey,         // 32
+    // Trusted secondary-market volume reporting (reporter = default disables)
+    pub volume_reporter: Pubkey,    // 32
+    pub secondary_volume: u64,      // 8
+    // Price drift with time: premium per day since created_at (0 = disabled)
+    pub created_at: i64,            // 8
+    pub time_premium_per_day: u64,  // 8
+    // Blind drop: editions stay frozen until reveal
+    pub frozen_until_reveal: bool,  // 1
+    pub revealed: bool,             // 1
+    // Soft-cap warning threshold (bps of max_supply, 0 = disabled), fired once
+    pub near_sellout_bps: u16,      // 2
+    pub near_sellout_emitted: bool, // 1
+    // Required edition mint decimals (0 = pure NFT)
+    pub edition_decimals: u8,       // 1
+    // Distinct wallets that have minted, and an optional cap on them (0 = unlimited)
+    pub unique_holders: u32,        // 4
+    pub max_unique_minters: u32,    // 4
+    // SPL payment token (None = native SOL) and flat lamport fee charged with it
+    pub payment_mint: Option<Pubkey>, // 1 + 32
+    pub flat_fee: u64,              // 8
+    pub fee_recipient: Pubkey,      // 32
+    // Cap on the final edition's price, checked on config changes (0 = disabled)
+    pub absolute_max_price: u64,    // 8
+    // KYC escrow mode: editions held until released (default = direct delivery)
+    pub compliance_authority: Pubkey, // 32
+    // All-or-nothing crowdfund (min_goal = 0 disables)
+    pub min_goal: u64,              // 8
+    pub deadline: i64,              // 8
+    // Mint proceeds held in the treasury PDA [b"treasury", curve]
+    pub treasury_enabled: bool,     // 1
+    pub treasury_bump: u8,          // 1
+    // Mint window (start_ts = 0 opens immediately, end_ts = 0 means no end)
+    pub start_ts: i64,              // 8
+    pub end_ts: i64,                // 8
+    // Creator proceeds go through the curve's RoyaltySplit account
+    pub royalty_split_enabled: bool, // 1
+    // Share of the buy-back liability (bps) the treasury vault must hold
+    pub reserve_ratio: u16,         // 2
+    // Seconds after minting during which a buyer may refund (0 = disabled)
+    pub refund_window: i64,         // 8
+    // Editions per price tier (Step curves; 0 prices every edition as its own tier)
+    pub tier_size: u32,             // 4
+    // Share of a referred mint's price paid to the referrer (bps, 0 = no reward)
+    pub referral_bps: u16,          // 2
+    // Soulbound: editions are frozen on mint until unfreeze_editions
+    pub freeze_on_mint: bool,       // 1
+    // Seconds a reserve_price hold stays valid (0 = holds disabled)
+    pub price_hold_secs: i64,       // 8
+    // Platform fees held in the treasury vault, withdrawable only by the platform admin
+    pub accrued_platform_fees: u64, // 8
+    // Transfer-hook program for Token-2022 editions (default = hooked mints disabled)
+    pub transfer_hook_program: Pubkey, // 32
+    // Minimum seconds between mints from one wallet (0 = no cooldown)
+    pub mint_cooldown_secs: u32,    // 4
+    // Creator profile the curve takes its fee settings from (default = not linked)
+    pub creator_profile: Pubkey,    // 32
+    // Hot wallet allowed to trigger mints, optionally the only way to mint
+    pub operator: Pubkey,           // 32
+    pub operator_only: bool,        // 1
+    // Price stops climbing once this many editions are sold (0 = never)
+    pub flat_after: u32,            // 4
+    // Team editions minted free from the public supply, and their cap
+    pub team_allocation_minted: u32, // 4
+    pub max_team_allocation: u32,   // 4
+    // Bezier prices come from paginated chunks rather than the single lookup
+    pub bezier_chunked: bool,       // 1
+    // Time of the latest paid mint, bounding how long refunds stay open
+    pub last_mint_at: i64,          // 8
+    // Lamports owed to outstanding editions on sell-back (sum of receipt net proceeds)
+    pub buyback_liability: u64,     // 8
+    // Editions minted into KYC escrow and not yet released
+    pub pending_escrows: u32,       // 4
+    // Seconds a reserve_specific reservation binds before it can be cancelled (0 = until the sale closes)
+    pub reservation_secs: i64,      // 8
+    // Vault lamports prepaid for unclaimed reservations
+    pub reserved_proceeds: u64,     // 8
+    // Lamports below the curve price forgone honouring price holds; kept out of
+    // total_volume, which only counts what buyers paid
+    pub held_price_shortfall: u64,  // 8
+    // Cap on mint_reserve editions (counted in reserved_minted)
+    pub max_reserve_mints: u32,     // 4
+}
+
+impl BondingCurve {
+    /// Mint proceeds are held in the treasury vault rather than paid to the creator
+    pub fn proceeds_to_treasury(&self) -> bool {
+        self.treasury_enabled || self.min_goal > 0
+    }
+
+    /// Crowdfund missed its goal by the deadline: mints stop and holders can refund
+    pub fn refunding(&self, now: i64) -> bool {
+        self.min_goal > 0 && now > self.deadline && self.total_volume < self.min_goal
+    }
+
+    /// The latest paid edition is still inside its refund window
+    pub fn refund_window_open(&self, now: i64) -> bool {
+        self.refund_window > 0 && now.saturating_sub(self.last_mint_at) <= self.refund_window
+    }
+
+    /// Crowdfund proceeds stay in the vault until the goal is met or all editions are refunded
+    pub fn crowdfund_locked(&self) -> bool {
+        self.min_goal > 0 && self.total_volume < self.min_goal && self.current_supply > 0
+    }
+
+    /// Newly minted editions are frozen: unrevealed blind drop or soulbound mode
+    pub fn editions_locked(&self) -> bool {
+        (self.frozen_until_reveal && !self.revealed) || self.freeze_on_mint
+    }
+}
+
+/// Return data for get_elasticity
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ElasticityMetrics {
+    pub current_price: u64,
+    pub projected_price: u64,
+    pub delta_supply: u32,
+    pub delta_bps: u64,
+}
+
+/// Return data for editions_for_budget
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BudgetQuote {
+    pub editions: u32,
+    pub total_cost: u64,
+}
+
+/// Return data for get_batch_quote
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BatchQuote {
+    pub total_cost: u64,
+    pub average_price: u64,
+    pub first_price: u64,
+    pub last_price: u64,
+    pub impact_bps: u64,
+}
+
+/// Return data for mint_edition: what was charged and the resulting supply
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintOutcome {
+    pub price: u64,
+    pub new_supply: u32,
+}
+
+/// Return data for get_edition_info
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EditionInfo {
+    pub edition: u32,
+    pub price_paid: u64,
+    pub slot: u64,
+}
+
+/// Return data for get_authority_info
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AuthorityInfo {
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+}
+
+/// Return data for get_lookup_info
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LookupInfo {
+    pub has_lookup: bool,
+    pub lookup_len: u32,
+}
+
+/// Return data for get_fee_breakdown; `referral`, `platform_fee`, `burn` and
+/// `net_to_creator` sum to `price`, and `flat_fee` lamports are charged on top
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    pub price: u64,
+    pub burn: u64,
+    pub net_to_creator: u64,
+    pub platform_fee: u64,
+    pub referral: u64,
+    pub flat_fee: u64,
+}
+
+/// Return data for get_curve_state; fields are only ever appended
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CurveState {
+    pub current_supply: u32,
+    pub max_supply: u32,
+    pub next_price: Option<u64>,
+    pub total_volume: u64,
+}
+
+/// Per-edition record: the curve an edition mint belongs to and what was paid
+#[account]
+#[derive(InitSpace)]
+pub struct MintReceipt {
+    pub bonding_curve: Pubkey,      // 32
+    pub edition_mint: Pubkey,       // 32
+    pub edition: u32,               // 4
+    pub price_paid: u64,            // 8
+    pub slot: u64,                  // 8 (slot of the mint, for provenance)
+    pub bump: u8,                   // 1
+    pub minted_at: i64,             // 8 (for the refund window)
+    pub net_proceeds: u64,          // 8 (lamports kept after burn and fees)
+    pub editions: u32,              // 4 (editions covered; a batch shares one receipt)
+    pub buyer: Pubkey,              // 32 (wallet that paid; only it can refund)
+}
+
+/// An edition held in the curve's escrow pending KYC release
+#[account]
+#[derive(InitSpace)]
+pub struct EditionEscrow {
+    pub bonding_curve: Pubkey,      // 32
+    pub buyer: Pubkey,              // 32
+    pub edition_mint: Pubkey,       // 32
+    pub bump: u8,                   // 1
+}
+
+/// Editions a wallet has minted from a curve and what it paid for them
+#[account]
+#[derive(InitSpace)]
+pub struct MintCounter {
+    pub bonding_curve: Pubkey,      // 32
+    pub buyer: Pubkey,              // 32
+    pub minted: u32,                // 4
+    pub total_paid: u64,            // 8
+    pub bump: u8,                   // 1
+    pub last_mint_ts: i64,          // 8 (for the mint cooldown)
+}
+
+/// Governance sign-off for raising a curve's max_supply
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceApproval {
+    pub bonding_curve: Pubkey,      // 32
+    pub approved_max_supply: u32,   // 4
+    pub bump: u8,                   // 1
+}
+
+/// Program-wide settings: the fee skimmed from each mint_edition payment and a kill switch
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformConfig {
+    pub admin: Pubkey,              // 32
+    pub platform_fee_bps: u16,      // 2
+    pub platform_wallet: Pubkey,    // 32
+    pub bump: u8,                   // 1
+    pub paused_globally: bool,      // 1 (kill switch for every curve)
+}
+
+/// Fee defaults an authority shares across its curves
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorProfile {
+    pub authority: Pubkey,          // 32
+    pub burn_bps: u16,              // 2
+    pub referral_bps: u16,          // 2
+    pub bump: u8,                   // 1
+}
+
+/// Per-buyer mint discount granted by the curve authority
+#[account]
+#[derive(InitSpace)]
+pub struct Coupon {
+    pub bonding_curve: Pubkey,      // 32
+    pub buyer: Pubkey,              // 32
+    pub discount_bps: u16,          // 2
+    pub uses_remaining: u8,         // 1
+    pub bump: u8,                   // 1
+}
+
+/// Next-edition price held for a buyer until `expires_at`
+#[account]
+#[derive(InitSpace)]
+pub struct PriceReservation {
+    pub bonding_curve: Pubkey,      // 32
+    pub buyer: Pubkey,              // 32
+    pub price: u64,                 // 8
+    pub expires_at: i64,            // 8
+    pub bump: u8,                   // 1
+}
+
+/// Lamports a buyer sets aside for relayed permit mints on one curve
+#[account]
+#[derive(InitSpace)]
+pub struct PermitDeposit {
+    pub bonding_curve: Pubkey,      // 32
+    pub buyer: Pubkey,              // 32
+    pub bump: u8,                   // 1
+}
+
+/// Replay guard for a consumed mint permit
+#[account]
+#[derive(InitSpace)]
+pub struct UsedPermit {
+    pub buyer: Pubkey,              // 32
+    pub nonce: u64,                 // 8
+    pub bump: u8,                   // 1
+}
+
+/// Recipients sharing a curve's creator proceeds; bps sum to 10000
+#[account]
+#[derive(InitSpace)]
+pub struct RoyaltySplit {
+    pub bonding_curve: Pubkey,      // 32
+    #[max_len(5)]
+    pub recipients: Vec<RoyaltyRecipient>, // 4 + 5 * 34
+    pub bump: u8,                   // 1
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct RoyaltyRecipient {
+    pub recipient: Pubkey,          // 32
+    pub bps: u16,                   // 2
+}
+
+/// Push price feed: micro-USD per SOL, published by its authority
 #[account]
 #[derive(InitSpace)]
-pub struct BondingCurve {
+pub struct PriceFeed {
     pub authority: Pubkey,          // 32
-    pub collection_mint: Pubkey,    // 32
-    pub curve_type: CurveType,      // 1
-    pub base_price: u64,            // 8
-    pub price_increment: u64,       // 8
-    pub max_supply: u32,            // 4
-    pub current_supply: u32,        // 4
-    pub total_volume: u64,          // 8
+    pub usd_per_sol: u64,           // 8
+    pub updated_at: i64,            // 8
     pub bump: u8,                   // 1
-    // Bezier curve: min and max prices
-    pub bezier_min_price: u64,      // 8
-    pub bezier_max_price: u64,      // 8
+}
+
+/// A specific edition number pre-paid by a buyer
+#[account]
+#[derive(InitSpace)]
+pub struct EditionReservation {
+    pub bonding_curve: Pubkey,      // 32
+    pub buyer: Pubkey,              // 32
+    pub edition: u32,               // 4
+    pub price_paid: u64,            // 8
+    pub bump: u8,                   // 1
+    pub net_proceeds: u64,          // 8
+    pub expires_at: i64,            // 8 (0 = binding until the sale closes)
 }
 
 /// Lookup table for pre-calculated Bezier prices
@@ -468,12 +7123,140 @@ pub struct BezierPriceLookup {
     pub bump: u8,                   // 1
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+/// One page of a Bezier lookup, for collections beyond a single account's 1000 prices
+#[account]
+#[derive(InitSpace)]
+pub struct BezierLookupChunk {
+    pub bonding_curve: Pubkey,      // 32
+    pub chunk_index: u32,           // 4
+    #[max_len(1000)]
+    pub prices: Vec<u64>,           // 4 + (n * 8), n <= BEZIER_CHUNK_SIZE
+    pub bump: u8,                   // 1
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
 pub enum CurveType {
     Linear,
     Exponential,
     Logarithmic,
     Bezier,
+    Constant,
+    Sigmoid,
+    DutchAuction,
+    Step,
+}
+
+impl CurveType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CurveType::Linear => "Linear",
+            CurveType::Exponential => "Exponential",
+            CurveType::Logarithmic => "Logarithmic",
+            CurveType::Bezier => "Bezier",
+            CurveType::Constant => "Constant",
+            CurveType::Sigmoid => "Sigmoid",
+            CurveType::DutchAuction => "DutchAuction",
+            CurveType::Step => "Step",
+        }
+    }
+
+    /// Curve types whose shape comes from `price_increment`
+    pub fn uses_increment(&self) -> bool {
+        matches!(
+            self,
+            CurveType::Linear
+                | CurveType::Exponential
+                | CurveType::Logarithmic
+                | CurveType::Sigmoid
+                | CurveType::DutchAuction
+                | CurveType::Step
+        )
+    }
+
+    /// Curve types that scale or count down from `base_price`, so zero is meaningless
+    pub fn needs_base_price(&self) -> bool {
+        matches!(self, CurveType::Exponential | CurveType::DutchAuction)
+    }
+}
+
+/// Mint instruction a fee breakdown is quoted for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MintPath {
+    /// mint_edition (and the other lamport paths without a referrer)
+    Standard,
+    /// mint_edition_with_referral
+    Referral,
+    /// mint_edition_dual_pay
+    DualPay,
+}
+
+/// Why a curve was paused or resumed, for indexers and UIs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub enum PauseReason {
+    None,
+    Maintenance,
+    MetadataIssue,
+    SecurityIncident,
+    Other,
+}
+
+#[event]
+pub struct Paused {
+    pub curve: Pubkey,
+    pub reason: PauseReason,
+}
+
+#[event]
+pub struct Unpaused {
+    pub curve: Pubkey,
+    pub reason: PauseReason,
+}
+
+#[event]
+pub struct SecondaryVolumeRecorded {
+    pub curve: Pubkey,
+    pub amount: u64,
+    pub secondary_volume: u64,
+}
+
+#[event]
+pub struct NearSellout {
+    pub curve: Pubkey,
+    pub current_supply: u32,
+    pub max_supply: u32,
+}
+
+#[event]
+pub struct EditionMinted {
+    pub curve: Pubkey,
+    pub edition_number: u32,
+    pub price: u64,
+    pub buyer: Pubkey,
+    pub new_supply: u32,
+    pub unique_buyers: u32,
+}
+
+#[event]
+pub struct EditionSold {
+    pub curve: Pubkey,
+    pub edition_number: u32,
+    pub price: u64,
+    pub seller: Pubkey,
+    pub new_supply: u32,
+}
+
+#[event]
+pub struct CurveUpdated {
+    pub curve: Pubkey,
+    pub base_price: u64,
+    pub price_increment: u64,
+    pub max_supply: u32,
+}
+
+#[event]
+pub struct CurveClosed {
+    pub curve: Pubkey,
+    pub collection_mint: Pubkey,
 }
 
 #[error_code]
@@ -482,7 +7265,7 @@ pub enum BondingCurveError {
     MaxSupplyReached,
     #[msg("Unauthorized: Only the authority can perform this action")]
     Unauthorized,
-    #[msg("Invalid max supply: must be greater than or equal to current supply")]
+    #[msg("Invalid max supply: must be non-zero, at most MAX_SUPPLY_CAP and at least current supply")]
     InvalidMaxSupply,
     #[msg("Curve is not empty: cannot close while supply exists")]
     CurveNotEmpty,
@@ -494,5 +7277,1137 @@ pub enum BondingCurveError {
     PriceNotFound,
     #[msg("Invalid curve type for this operation")]
     InvalidCurveType,
+    #[msg("Edition is already minted or beyond max supply")]
+    InvalidEdition,
+    #[msg("Next edition is reserved and must be claimed via claim_reserved")]
+    EditionReserved,
+    #[msg("Reservation account for the next edition is required")]
+    ReservationAccountRequired,
+    #[msg("Reserved edition is not next in line yet")]
+    ReservationNotReady,
+    #[msg("Invalid fee: basis points cannot exceed 10000")]
+    InvalidFeeBps,
+    #[msg("Incinerator account is required when a burn fee is set")]
+    IncineratorRequired,
+    #[msg("Curve type cannot be changed after initialization")]
+    CurveTypeImmutable,
+    #[msg("Sell-backs have been permanently disabled for this curve")]
+    SellbackDisabled,
+    #[msg("The configured price feed account is required")]
+    OracleRequired,
+    #[msg("Price feed is stale or invalid")]
+    OracleStale,
+    #[msg("Vault still backs sell-backs: disable sell-backs or wait for zero supply")]
+    VaultStillBacking,
+    #[msg("Minting is paused for this curve")]
+    CurvePaused,
+    #[msg("Price is never reached within max supply")]
+    PriceUnreachable,
+    #[msg("Invalid period: must not be negative")]
+    InvalidPeriod,
+    #[msg("Mint cap for the current period has been reached")]
+    PeriodCapReached,
+    #[msg("Permit has expired")]
+    PermitExpired,
+    #[msg("Permit signature is missing or does not match the mint parameters")]
+    InvalidPermit,
+    #[msg("Price exceeds the buyer's maximum price")]
+    SlippageExceeded,
+    #[msg("Max supply cannot drop below minted plus reserved editions")]
+    MaxSupplyBelowReserved,
+    #[msg("Price increment must be non-zero for this curve type; use Constant for flat pricing")]
+    ZeroIncrementNotAllowed,
+    #[msg("No allowlist is configured for this curve")]
+    AllowlistNotConfigured,
+    #[msg("Wallet is not on the allowlist or the proof is invalid")]
+    NotWhitelisted,
+    #[msg("Wallet has minted its allowlisted quantity")]
+    AllowlistQuantityExceeded,
+    #[msg("Quantity must be non-zero and within the remaining supply")]
+    InvalidQuantity,
+    #[msg("Raising max supply requires a covering governance approval")]
+    GovernanceApprovalRequired,
+    #[msg("Governance is already configured for this curve")]
+    GovernanceAlreadySet,
+    #[msg("Edition mint is already bound to another curve")]
+    MintBoundToOtherCurve,
+    #[msg("Collection has already been revealed")]
+    AlreadyRevealed,
+    #[msg("Collection has not been revealed yet")]
+    NotRevealed,
+    #[msg("Invalid threshold: basis points cannot exceed 10000")]
+    InvalidThresholdBps,
+    #[msg("Edition mint decimals do not match the curve's configured decimals")]
+    InvalidMintDecimals,
+    #[msg("Unique minter limit reached: new wallets cannot mint")]
+    MinterLimitReached,
+    #[msg("Target time must be in the future")]
+    InvalidTargetTime,
+    #[msg("Payment token does not match the curve's payment mint")]
+    PaymentMintMismatch,
+    #[msg("Curve's final edition price exceeds the absolute max price")]
+    CurveExceedsMaxPrice,
+    #[msg("Curve requires KYC: mint via mint_edition_escrowed")]
+    EscrowRequired,
+    #[msg("KYC escrow is not enabled for this curve")]
+    EscrowNotEnabled,
+    #[msg("Treasury account is required when proceeds are held by the curve")]
+    TreasuryRequired,
+    #[msg("Crowdfund goal was missed: the drop is refunding")]
+    RefundMode,
+    #[msg("Drop is not in refund mode")]
+    NotRefunding,
+    #[msg("Crowdfund proceeds are locked until the goal is met")]
+    FundsLocked,
+    #[msg("Sell-backs are temporarily paused")]
+    SellbackPaused,
+    #[msg("Sell-backs are not enabled: the vault is not funded")]
+    SellbackNotEnabled,
+    #[msg("Withdrawal would leave the vault unable to back outstanding editions")]
+    InsufficientReserve,
+    #[msg("Payment mint and token accounts are required for SPL payment")]
+    PaymentAccountsRequired,
+    #[msg("Minting has not started yet")]
+    MintNotStarted,
+    #[msg("Minting has ended")]
+    MintEnded,
+    #[msg("Invalid mint window: end must be after start")]
+    InvalidMintWindow,
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+    #[msg("Royalty split must have 1-5 recipients whose bps sum to 10000")]
+    InvalidRoyaltySplit,
+    #[msg("Royalty split account is required for this curve")]
+    RoyaltySplitRequired,
+    #[msg("Royalty recipient accounts do not match the split")]
+    RoyaltyRecipientMismatch,
+    #[msg("Lookup entries for already-minted editions cannot change")]
+    LookupEntryMinted,
+    #[msg("Coupon has no uses remaining")]
+    CouponExhausted,
+    #[msg("Platform wallet account is missing or does not match the platform config")]
+    PlatformWalletRequired,
+    #[msg("Invalid base price: this curve type needs a non-zero base price")]
+    InvalidBasePrice,
+    #[msg("Refund window for this edition has closed")]
+    RefundWindowClosed,
+    #[msg("Tier size must be greater than zero")]
+    InvalidTierSize,
+    #[msg("Edition mint already has supply: 1/1 editions need a fresh mint")]
+    InvalidEditionMint,
+    #[msg("Minting is paused platform-wide")]
+    GloballyPaused,
+    #[msg("Buyers cannot refer themselves")]
+    SelfReferral,
+    #[msg("Editions are soulbound until the authority unfreezes them")]
+    EditionsLocked,
+    #[msg("Editions are not frozen on mint")]
+    EditionsNotLocked,
+    #[msg("Invalid Bezier range: min price cannot exceed max price")]
+    InvalidBezierRange,
+    #[msg("Price holds are not enabled for this curve")]
+    PriceHoldDisabled,
+    #[msg("Price reservation has expired")]
+    ReservationExpired,
+    #[msg("No platform fees have accrued in the vault")]
+    NoFeesAccrued,
+    #[msg("Accrued platform fees must be withdrawn first")]
+    PlatformFeesOutstanding,
+    #[msg("No transfer hook program is configured for this curve")]
+    TransferHookNotConfigured,
+    #[msg("Mint cooldown for this wallet is still active")]
+    CooldownActive,
+    #[msg("Creator profile is not the one linked to this curve")]
+    CreatorProfileMismatch,
+    #[msg("Mints on this curve must be triggered by its operator")]
+    OperatorRequired,
+    #[msg("Team allocation would exceed max_team_allocation")]
+    TeamAllocationExceeded,
+    #[msg("Withdrawal would leave the treasury below its rent-exempt minimum")]
+    InsufficientTreasuryBalance,
+    #[msg("Invalid Sigmoid range: base price cannot exceed the ceiling")]
+    InvalidSigmoidRange,
+    #[msg("Curve already prices from the other Bezier lookup layout")]
+    BezierLookupConflict,
+    #[msg("Free team and reserve editions cannot be sold back or refunded")]
+    UnpaidEdition,
+    #[msg("Invalid discount: cannot exceed 100%")]
+    InvalidDiscountBps,
+    #[msg("Semi-fungible editions share one receipt and cannot be sold back or refunded")]
+    SharedReceipt,
+    #[msg("Token proceeds must go to the treasury vault's associated token account")]
+    VaultPaymentAccountMismatch,
+    #[msg("Permit deposit does not cover the price")]
+    InsufficientDeposit,
+    #[msg("Reservation has not expired and the sale is still open")]
+    ReservationActive,
+    #[msg("Reserve mints would exceed max_reserve_mints")]
+    ReserveAllocationExceeded,
+    #[msg("Too many editions to price one by one; quote a shorter range")]
+    PriceSumTooLong,
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::entrypoint::SUCCESS;
+    use anchor_spl::associated_token::spl_associated_token_account::solana_program::program_stubs;
+    use std::cell::Cell;
+
+    thread_local! {
+        static NOW: Cell<i64> = const { Cell::new(0) };
+    }
+
+    // Off-chain stand-ins for the syscalls the program makes; state is per test thread
+    struct TestSyscalls;
+
+    impl program_stubs::SyscallStubs for TestSyscalls {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                unix_timestamp: NOW.with(Cell::get),
+                ..Clock::default()
+            };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            SUCCESS
+        }
+    }
+
+    fn set_clock(unix_timestamp: i64) {
+        static INSTALL: std::sync::Once = std::sync::Once::new();
+        INSTALL.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(TestSyscalls));
+        });
+        NOW.with(|now| now.set(unix_timestamp));
+    }
+
+    // A curve account as it looks straight after `init`, before any configuration
+    fn blank_curve(curve_type: CurveType) -> BondingCurve {
+        let zeroed = vec![0u8; 8 + BondingCurve::INIT_SPACE];
+        let mut curve = BondingCurve::try_deserialize_unchecked(&mut &zeroed[..]).unwrap();
+        curve.curve_type = curve_type;
+        curve.max_supply = 100;
+        curve
+    }
+
+    fn assert_overflow(result: Result<u64>) {
+        assert_eq!(
+            result.unwrap_err(),
+            anchor_lang::error::Error::from(BondingCurveError::ArithmeticOverflow)
+        );
+    }
+
+    // A leaked account, so it lives as long as the `'info` account validation expects
+    fn leaked_account(
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+        is_signer: bool,
+        executable: bool,
+    ) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            is_signer,
+            !executable,
+            Box::leak(Box::new(lamports)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            executable,
+            0,
+        )
+    }
+
+    // Sorted-pair parent, matching verify_merkle_proof
+    fn merkle_parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[1u8], &left, &right]).to_bytes()
+    }
+
+    #[test]
+    fn supply_offset_shifts_the_curve() {
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.base_price = 100;
+        curve.price_increment = 10;
+        let unshifted = calculate_price(&curve, 11).unwrap();
+
+        // Edition 1 of a curve continuing from 10 prior editions prices as edition 11
+        curve.supply_offset = 10;
+        assert_eq!(calculate_price(&curve, 1).unwrap(), unshifted);
+        assert_eq!(calculate_price(&curve, 1).unwrap(), 200);
+        assert_eq!(calculate_price(&curve, 2).unwrap(), 210);
+    }
+
+    #[test]
+    fn fixed_pow_matches_integer_powers() {
+        assert_eq!(fixed_pow(7 * FIXED_POINT_SCALE, 0).unwrap(), FIXED_POINT_SCALE);
+        assert_eq!(fixed_pow(2 * FIXED_POINT_SCALE, 10).unwrap(), 1024 * FIXED_POINT_SCALE);
+        assert_eq!(fixed_pow(FIXED_POINT_SCALE, 1_000_000).unwrap(), FIXED_POINT_SCALE);
+        // 1.5^2 = 2.25
+        assert_eq!(
+            fixed_pow(3 * FIXED_POINT_SCALE / 2, 2).unwrap(),
+            9 * FIXED_POINT_SCALE / 4
+        );
+        assert!(fixed_pow(2 * FIXED_POINT_SCALE, 200).is_err());
+    }
+
+    #[test]
+    fn exponential_matches_off_chain_compounding() {
+        let mut curve = blank_curve(CurveType::Exponential);
+        curve.base_price = 1_000_000_000;
+        curve.price_increment = 500; // 5% per edition
+
+        // 1e9 * 1.05^(edition - 1), evaluated off-chain at 50 digits
+        for (edition, reference) in [
+            (1u32, 1_000_000_000u64),
+            (10, 1_551_328_215),
+            (100, 125_239_293_186),
+        ] {
+            let price = calculate_price(&curve, edition).unwrap();
+            assert!(
+                price.abs_diff(reference) <= reference / 1_000_000,
+                "edition {}: {} vs {}",
+                edition,
+                price,
+                reference
+            );
+        }
+    }
+
+    #[test]
+    fn fixed_log2_is_exact_on_powers_and_strictly_increasing() {
+        assert_eq!(fixed_log2(0), 0);
+        assert_eq!(fixed_log2(1), 0);
+        assert_eq!(fixed_log2(2), FIXED_POINT_SCALE);
+        assert_eq!(fixed_log2(1024), 10 * FIXED_POINT_SCALE);
+
+        let mut previous = fixed_log2(1);
+        for x in 2..=10_000u32 {
+            let current = fixed_log2(x);
+            assert!(current > previous, "log2 not increasing at {}", x);
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn logarithmic_prices_rise_smoothly_and_deterministically() {
+        let mut curve = blank_curve(CurveType::Logarithmic);
+        curve.base_price = 1_000_000;
+        curve.price_increment = 1_000_000;
+
+        let prices: Vec<u64> = (1..=64).map(|e| calculate_price(&curve, e).unwrap()).collect();
+        assert!(prices.windows(2).all(|w| w[0] <= w[1]));
+        // Editions 4 through 7 no longer share one price
+        assert!(prices[3] < prices[4] && prices[5] < prices[6]);
+
+        // A second evaluation (another validator) lands on identical lamports
+        let again: Vec<u64> = (1..=64).map(|e| calculate_price(&curve, e).unwrap()).collect();
+        assert_eq!(prices, again);
+        assert_eq!(prices[63], 7_000_000);
+    }
+
+    #[test]
+    fn bezier_chunks_cover_consecutive_editions() {
+        assert_eq!(bezier_chunk_slot(0), (0, 0));
+        assert_eq!(bezier_chunk_slot(BEZIER_CHUNK_SIZE - 1), (0, BEZIER_CHUNK_SIZE as usize - 1));
+        assert_eq!(bezier_chunk_slot(BEZIER_CHUNK_SIZE), (1, 0));
+        assert_eq!(bezier_chunk_slot(2 * BEZIER_CHUNK_SIZE + 7), (2, 7));
+
+        // Chunk `i` starts where initialize_bezier_chunk places it
+        for edition_idx in [0, 1, 999, 1_000, 1_001, 123_456] {
+            let (chunk, offset) = bezier_chunk_slot(edition_idx);
+            assert_eq!(chunk * BEZIER_CHUNK_SIZE + offset as u32, edition_idx);
+        }
+    }
+
+    #[test]
+    fn bezier_interpolation_hits_both_endpoints() {
+        let mut curve = blank_curve(CurveType::Bezier);
+        curve.bezier_min_price = 1_000;
+        curve.bezier_max_price = 9_000;
+
+        assert_eq!(calculate_price(&curve, 1).unwrap(), 1_000);
+        assert_eq!(calculate_price(&curve, curve.max_supply).unwrap(), 9_000);
+
+        // A single edition is priced at the minimum
+        curve.max_supply = 1;
+        assert_eq!(calculate_price(&curve, 1).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn whitelist_proofs_admit_members_only() {
+        let wallets: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = wallets.iter().map(whitelist_leaf).collect();
+        let left = merkle_parent(leaves[0], leaves[1]);
+        let right = merkle_parent(leaves[2], leaves[3]);
+        let root = merkle_parent(left, right);
+
+        assert!(verify_merkle_proof(&[leaves[1], right], root, leaves[0]));
+        assert!(verify_merkle_proof(&[leaves[0], right], root, leaves[1]));
+        assert!(verify_merkle_proof(&[leaves[3], left], root, leaves[2]));
+        assert!(verify_merkle_proof(&[leaves[2], left], root, leaves[3]));
+
+        // A non-member cannot reuse a member's proof
+        let stranger = whitelist_leaf(&Pubkey::new_unique());
+        assert!(!verify_merkle_proof(&[leaves[1], right], root, stranger));
+        assert!(!verify_merkle_proof(&[leaves[3], left], root, stranger));
+        assert!(!verify_merkle_proof(&[], root, leaves[0]));
+    }
+
+    #[test]
+    fn allowlist_proofs_bind_the_wallet_quantity() {
+        let wallets: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = wallets.iter().map(|w| allowlist_leaf(w, 3)).collect();
+        let left = merkle_parent(leaves[0], leaves[1]);
+        let right = merkle_parent(leaves[2], leaves[3]);
+        let root = merkle_parent(left, right);
+
+        assert!(verify_merkle_proof(&[leaves[1], right], root, allowlist_leaf(&wallets[0], 3)));
+
+        // Claiming a larger quantity, or no quantity, breaks the proof
+        assert!(!verify_merkle_proof(&[leaves[1], right], root, allowlist_leaf(&wallets[0], 4)));
+        assert!(!verify_merkle_proof(&[leaves[1], right], root, allowlist_leaf(&wallets[0], u32::MAX)));
+        assert!(!verify_merkle_proof(&[leaves[1], right], root, whitelist_leaf(&wallets[0])));
+    }
+
+    #[test]
+    fn every_curve_type_reports_overflow() {
+        set_clock(86_400);
+        for curve_type in [
+            CurveType::Linear,
+            CurveType::Exponential,
+            CurveType::Logarithmic,
+            CurveType::Bezier,
+            CurveType::Constant,
+            CurveType::Sigmoid,
+            CurveType::DutchAuction,
+            CurveType::Step,
+        ] {
+            let mut curve = blank_curve(curve_type);
+            curve.base_price = u64::MAX;
+            curve.price_increment = 1;
+            curve.tier_size = 1;
+            // Bezier interpolates between its own bounds rather than from base_price
+            curve.bezier_min_price = u64::MAX;
+            curve.bezier_max_price = u64::MAX;
+            // A day-old curve adds a one-lamport premium; the auction has just opened
+            curve.time_premium_per_day = 1;
+            curve.start_ts = 86_400;
+            assert_overflow(calculate_price(&curve, 2));
+        }
+
+        // Supply-driven curves overflow in their own branch, without the premium
+        for curve_type in [
+            CurveType::Linear,
+            CurveType::Exponential,
+            CurveType::Logarithmic,
+            CurveType::Sigmoid,
+            CurveType::Step,
+        ] {
+            let mut curve = blank_curve(curve_type);
+            curve.base_price = u64::MAX;
+            curve.price_increment = 1;
+            curve.tier_size = 1;
+            assert_overflow(calculate_price(&curve, 2));
+        }
+    }
+
+    #[test]
+    fn sigmoid_stays_between_floor_and_ceiling() {
+        let mut curve = blank_curve(CurveType::Sigmoid);
+        curve.base_price = 1_000;
+        curve.bezier_max_price = 5_000;
+
+        for steepness in [1u64, 10_000, 50_000] {
+            curve.price_increment = steepness;
+            let mut previous = 0;
+            for edition in 1..=curve.max_supply {
+                let price = calculate_price(&curve, edition).unwrap();
+                assert!((1_000..=5_000).contains(&price));
+                assert!(price >= previous);
+                previous = price;
+            }
+        }
+
+        // Inflection at max_supply / 2, ceiling reached near max supply on a steep ramp
+        curve.price_increment = 100_000;
+        assert_eq!(calculate_price(&curve, 50).unwrap(), 3_000);
+        assert_eq!(calculate_price(&curve, 95).unwrap(), 5_000);
+        assert_eq!(calculate_price(&curve, 5).unwrap(), 1_000);
+
+        assert_eq!(sigmoid_fraction(1, 100, 100_000), 0);
+        assert_eq!(sigmoid_fraction(100, 100, 100_000), FIXED_POINT_SCALE);
+        assert_eq!(sigmoid_fraction(50, 100, 10_000), FIXED_POINT_SCALE / 2);
+    }
+
+    #[test]
+    fn payment_split_takes_the_platform_fee_off_the_top() {
+        let mut curve = blank_curve(CurveType::Linear);
+        let config = PlatformConfig {
+            admin: Pubkey::new_unique(),
+            platform_fee_bps: 250,
+            platform_wallet: Pubkey::new_unique(),
+            bump: 255,
+            paused_globally: false,
+        };
+        let mut data = Vec::new();
+        config.try_serialize(&mut data).unwrap();
+        let (key, mut lamports) = (Pubkey::new_unique(), 0);
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &crate::ID, false, 0);
+        let platform_config = UncheckedAccount::try_from(&info);
+
+        // 2.5% to the platform, rounded down in the creator's favour
+        assert_eq!(
+            split_payment(&curve, &platform_config, 1_000_000_001).unwrap(),
+            PaymentSplit { platform_fee: 25_000_000, burn: 0, net: 975_000_001 }
+        );
+
+        // The burn share comes out of what is left after the fee
+        curve.burn_bps = 1000;
+        assert_eq!(
+            split_payment(&curve, &platform_config, 1_000_000_000).unwrap(),
+            PaymentSplit { platform_fee: 25_000_000, burn: 97_500_000, net: 877_500_000 }
+        );
+
+        // No platform fee until the config exists
+        let (mut empty, mut lamports) = (Vec::new(), 0);
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut empty, &crate::ID, false, 0);
+        assert_eq!(
+            split_payment(&curve, &UncheckedAccount::try_from(&info), 1_000_000_000).unwrap(),
+            PaymentSplit { platform_fee: 0, burn: 100_000_000, net: 900_000_000 }
+        );
+    }
+
+    #[test]
+    fn operator_cannot_act_as_authority() {
+        let operator = Pubkey::new_unique();
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.authority = Pubkey::new_unique();
+        curve.operator = operator;
+        curve.collection_mint = Pubkey::new_unique();
+        let (curve_key, bump) = Pubkey::find_program_address(
+            &[b"bonding_curve", curve.collection_mint.as_ref()],
+            &crate::ID,
+        );
+        curve.bump = bump;
+        let treasury = Pubkey::find_program_address(&[b"treasury", curve_key.as_ref()], &crate::ID).0;
+        let mut data = Vec::new();
+        curve.try_serialize(&mut data).unwrap();
+
+        let curve_info = leaked_account(curve_key, crate::ID, 1, data, false, false);
+        let operator_info = leaked_account(operator, system_program::ID, 1_000_000_000, vec![], true, false);
+        let treasury_info = leaked_account(treasury, system_program::ID, 1_000_000_000, vec![], false, false);
+        let program_info = leaked_account(crate::ID, Pubkey::default(), 1, vec![], false, true);
+        let system_info = leaked_account(system_program::ID, Pubkey::default(), 1, vec![], false, true);
+        let unauthorized = || anchor_lang::error::Error::from(BondingCurveError::Unauthorized);
+
+        // update_curve and its setters
+        let infos: &'static [AccountInfo<'static>] =
+            Box::leak(vec![curve_info.clone(), operator_info.clone(), program_info].into_boxed_slice());
+        let result = UpdateCurve::try_accounts(
+            &crate::ID,
+            &mut &infos[..],
+            &[],
+            &mut UpdateCurveBumps::default(),
+            &mut std::collections::BTreeSet::new(),
+        );
+        assert_eq!(result.err(), Some(unauthorized()));
+
+        // withdraw_treasury
+        // No token accounts: the optional slots are filled with the program id
+        let none = leaked_account(crate::ID, Pubkey::default(), 1, vec![], false, true);
+        let infos: &'static [AccountInfo<'static>] = Box::leak(
+            vec![curve_info, treasury_info, none.clone(), none.clone(), operator_info, none, system_info]
+                .into_boxed_slice(),
+        );
+        let result = SweepVault::try_accounts(
+            &crate::ID,
+            &mut &infos[..],
+            &[],
+            &mut SweepVaultBumps::default(),
+            &mut std::collections::BTreeSet::new(),
+        );
+        assert_eq!(result.err(), Some(unauthorized()));
+    }
+
+    // The receipt for one paid edition
+    fn paid_receipt(buyer: Pubkey, edition: u32, price_paid: u64, net_proceeds: u64, minted_at: i64) -> MintReceipt {
+        MintReceipt {
+            bonding_curve: Pubkey::new_unique(),
+            edition_mint: Pubkey::new_unique(),
+            edition,
+            price_paid,
+            slot: 0,
+            bump: 255,
+            minted_at,
+            net_proceeds,
+            editions: 1,
+            buyer,
+        }
+    }
+
+    #[test]
+    fn refunds_go_only_to_the_buyer_of_a_whole_edition() {
+        let buyer = Pubkey::new_unique();
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.refund_window = 3_600;
+        let receipt = paid_receipt(buyer, 1, 1_000_000, 1_000_000, 10_000);
+        let error = |e: BondingCurveError| Some(anchor_lang::error::Error::from(e));
+
+        assert!(check_refundable(&curve, &receipt, buyer, 10_000 + 3_600).is_ok());
+        assert_eq!(
+            check_refundable(&curve, &receipt, buyer, 10_000 + 3_601).err(),
+            error(BondingCurveError::RefundWindowClosed)
+        );
+        assert_eq!(
+            check_refundable(&curve, &receipt, Pubkey::new_unique(), 10_000).err(),
+            error(BondingCurveError::Unauthorized)
+        );
+
+        // Fractional holders share the receipt of whoever minted last
+        curve.edition_decimals = 2;
+        assert_eq!(
+            check_refundable(&curve, &receipt, buyer, 10_000).err(),
+            error(BondingCurveError::SharedReceipt)
+        );
+    }
+
+    #[test]
+    fn failed_crowdfund_refunds_the_full_price_and_frees_the_vault() {
+        let buyer = Pubkey::new_unique();
+        let mut curve = blank_curve(CurveType::Constant);
+        curve.min_goal = 10_000_000;
+        curve.deadline = 1_000;
+        // Two editions at 1_000_000 with a 2.5% platform fee accrued in the vault
+        let receipts = [
+            paid_receipt(buyer, 1, 1_000_000, 975_000, 500),
+            paid_receipt(buyer, 2, 1_000_000, 975_000, 600),
+        ];
+        curve.current_supply = 2;
+        curve.total_volume = 2_000_000;
+        curve.buyback_liability = 1_950_000;
+        curve.accrued_platform_fees = 50_000;
+
+        // Goal missed: the authority's withdrawal and the fee claim stay locked
+        let now = 1_001;
+        set_clock(now);
+        assert!(curve.refunding(now));
+        assert!(curve.crowdfund_locked());
+
+        for receipt in &receipts {
+            check_refundable(&curve, receipt, buyer, now).unwrap();
+            assert_eq!(settle_refund(&mut curve, receipt, now).unwrap(), receipt.price_paid);
+        }
+
+        assert_eq!(curve.current_supply, 0);
+        assert_eq!(curve.total_volume, 0);
+        assert_eq!(curve.buyback_liability, 0);
+        assert_eq!(curve.accrued_platform_fees, 0);
+        assert!(ensure_vault_sweepable(&curve).is_ok());
+    }
+
+    // A leaked SPL token account holding `amount` of `mint` for `owner`
+    fn token_account(key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> Account<'static, TokenAccount> {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token::spl_token::state::{Account as SplAccount, AccountState};
+
+        let mut data = vec![0u8; SplAccount::LEN];
+        SplAccount {
+            mint,
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            ..SplAccount::default()
+        }
+        .pack_into_slice(&mut data);
+        let info = leaked_account(key, anchor_spl::token::ID, 2_039_280, data, false, false);
+        Account::try_from(Box::leak(Box::new(info))).unwrap()
+    }
+
+    #[test]
+    fn spl_proceeds_are_held_in_the_vault_token_account() {
+        let payment_mint = Pubkey::new_unique();
+        let buyer = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        let vault_key = anchor_spl::associated_token::get_associated_token_address(&treasury, &payment_mint);
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.payment_mint = Some(payment_mint);
+        curve.treasury_enabled = true;
+        curve.base_price = 5_000_000;
+        let price = calculate_price(&curve, 1).unwrap();
+
+        // The buyer is debited exactly the price: no platform fee configured, no burn
+        let platform_config = UncheckedAccount::try_from(Box::leak(Box::new(leaked_account(
+            Pubkey::new_unique(),
+            system_program::ID,
+            0,
+            vec![],
+            false,
+            false,
+        ))));
+        let payment = split_payment(&curve, &platform_config, price).unwrap();
+        assert_eq!(payment, PaymentSplit { platform_fee: 0, burn: 0, net: price });
+
+        // ... and the net lands in the vault's associated token account, where the
+        // sell-back and refund paths draw it from
+        let vault = Some(token_account(vault_key, payment_mint, treasury, 0));
+        assert_eq!(vault_payment_account(treasury, payment_mint, &vault).unwrap().key(), vault_key);
+
+        let buyer_account = Some(token_account(Pubkey::new_unique(), payment_mint, buyer, price));
+        assert_eq!(
+            vault_payment_account(treasury, payment_mint, &buyer_account).err(),
+            Some(anchor_lang::error::Error::from(BondingCurveError::VaultPaymentAccountMismatch))
+        );
+        assert_eq!(
+            vault_payment_account(treasury, payment_mint, &None).err(),
+            Some(anchor_lang::error::Error::from(BondingCurveError::PaymentAccountsRequired))
+        );
+    }
+
+    // An instructions sysvar holding an ed25519 verification of `message` by
+    // `signer`, followed by the current (bonding curve) instruction
+    #[allow(deprecated)]
+    fn instructions_with_ed25519(signer: &Pubkey, message: &[u8]) -> AccountInfo<'static> {
+        use anchor_lang::solana_program::sysvar::instructions::{
+            construct_instructions_data, BorrowedInstruction,
+        };
+
+        // Header, then pubkey at 16, signature at 48 and message at 112
+        let mut data = vec![1u8, 0];
+        for offset in [48u16, u16::MAX, 16, u16::MAX, 112, message.len() as u16, u16::MAX] {
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(message);
+
+        let mut sysvar = construct_instructions_data(&[
+            BorrowedInstruction { program_id: &ED25519_PROGRAM_ID, accounts: vec![], data: &data },
+            BorrowedInstruction { program_id: &crate::ID, accounts: vec![], data: &[] },
+        ]);
+        // The current instruction index is stored in the last two bytes
+        let len = sysvar.len();
+        sysvar[len - 2..].copy_from_slice(&1u16.to_le_bytes());
+        leaked_account(
+            anchor_lang::solana_program::sysvar::instructions::ID,
+            Pubkey::default(),
+            1,
+            sysvar,
+            false,
+            false,
+        )
+    }
+
+    #[test]
+    fn permit_binds_the_curve_edition_mint_and_relayer() {
+        let (curve, edition_mint, buyer, relayer) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let signed = permit_message(&curve, &edition_mint, &buyer, &relayer, 1_000_000, 10_000, 7);
+        let sysvar = instructions_with_ed25519(&buyer, &signed);
+        let forged = || Some(anchor_lang::error::Error::from(BondingCurveError::InvalidPermit));
+
+        assert!(verify_ed25519_permit(&sysvar, &buyer, &signed).is_ok());
+
+        // Any other edition mint, curve or relayer needs a new signature
+        let other = Pubkey::new_unique();
+        for message in [
+            permit_message(&curve, &other, &buyer, &relayer, 1_000_000, 10_000, 7),
+            permit_message(&other, &edition_mint, &buyer, &relayer, 1_000_000, 10_000, 7),
+            permit_message(&curve, &edition_mint, &buyer, &other, 1_000_000, 10_000, 7),
+        ] {
+            assert_eq!(verify_ed25519_permit(&sysvar, &buyer, &message).err(), forged());
+        }
+
+        // ... and a signature by anyone but the buyer is rejected
+        let sysvar = instructions_with_ed25519(&other, &signed);
+        assert_eq!(verify_ed25519_permit(&sysvar, &buyer, &signed).err(), forged());
+    }
+
+    #[test]
+    fn reserved_edition_is_gated_like_a_mint_and_cancellable_once_stale() {
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.max_supply = 1000;
+        curve.base_price = 1_000_000;
+        curve.price_increment = 10_000;
+        curve.treasury_enabled = true;
+        let platform_config = UncheckedAccount::try_from(Box::leak(Box::new(leaked_account(
+            Pubkey::new_unique(),
+            crate::ID,
+            0,
+            vec![],
+            false,
+            false,
+        ))));
+        set_clock(100);
+
+        // #777 is paid for now, at its own position on the curve
+        check_reservable(&mut curve, &platform_config).unwrap();
+        let reservation = EditionReservation {
+            bonding_curve: Pubkey::new_unique(),
+            buyer: Pubkey::new_unique(),
+            edition: 777,
+            price_paid: calculate_price(&curve, 777).unwrap(),
+            bump: 255,
+            net_proceeds: calculate_price(&curve, 777).unwrap(),
+            expires_at: 100 + 3_600,
+        };
+        assert_eq!(reservation.price_paid, 1_000_000 + 776 * 10_000);
+
+        // ... and stays binding until it expires or the sale closes without reaching it
+        assert!(!reservation_cancellable(&curve, &reservation, 200));
+        assert!(reservation_cancellable(&curve, &reservation, 100 + 3_601));
+        curve.end_ts = 150;
+        assert!(reservation_cancellable(&curve, &reservation, 200));
+
+        // Reserving is closed wherever a public mint would be
+        curve.end_ts = 0;
+        curve.operator_only = true;
+        assert_eq!(
+            check_reservable(&mut curve, &platform_config).err(),
+            Some(anchor_lang::error::Error::from(BondingCurveError::OperatorRequired))
+        );
+        curve.operator_only = false;
+        curve.min_goal = u64::MAX;
+        curve.deadline = 50;
+        assert_eq!(
+            check_reservable(&mut curve, &platform_config).err(),
+            Some(anchor_lang::error::Error::from(BondingCurveError::RefundMode))
+        );
+    }
+
+    #[test]
+    fn fee_breakdown_follows_the_mint_path() {
+        let mut curve = blank_curve(CurveType::Constant);
+        curve.referral_bps = 500;
+        curve.burn_bps = 1000;
+        curve.flat_fee = 5_000;
+        let config = PlatformConfig {
+            admin: Pubkey::new_unique(),
+            platform_fee_bps: 250,
+            platform_wallet: Pubkey::new_unique(),
+            bump: 255,
+            paused_globally: false,
+        };
+        let mut data = Vec::new();
+        config.try_serialize(&mut data).unwrap();
+        let platform_config = UncheckedAccount::try_from(Box::leak(Box::new(leaked_account(
+            Pubkey::new_unique(),
+            crate::ID,
+            0,
+            data,
+            false,
+            false,
+        ))));
+
+        // The referrer is paid first; the platform fee and burn come out of the rest
+        let referral = fee_breakdown(&curve, &platform_config, MintPath::Referral, 1_000_000).unwrap();
+        assert_eq!(
+            referral,
+            FeeBreakdown {
+                price: 1_000_000,
+                burn: 92_625,
+                net_to_creator: 833_625,
+                platform_fee: 23_750,
+                referral: 50_000,
+                flat_fee: 0,
+            }
+        );
+        assert_eq!(
+            referral.referral + referral.platform_fee + referral.burn + referral.net_to_creator,
+            referral.price
+        );
+
+        // No referral on a plain mint; the dual-pay flat fee is on top of the price
+        let standard = fee_breakdown(&curve, &platform_config, MintPath::Standard, 1_000_000).unwrap();
+        assert_eq!((standard.referral, standard.platform_fee, standard.flat_fee), (0, 25_000, 0));
+        let dual_pay = fee_breakdown(&curve, &platform_config, MintPath::DualPay, 1_000_000).unwrap();
+        assert_eq!(dual_pay.net_to_creator, standard.net_to_creator);
+        assert_eq!(dual_pay.flat_fee, 5_000);
+
+        // A coupon is quoted without being used up
+        let coupon = Coupon {
+            bonding_curve: Pubkey::new_unique(),
+            buyer: Pubkey::new_unique(),
+            discount_bps: 2_000,
+            uses_remaining: 1,
+            bump: 255,
+        };
+        assert_eq!(coupon_discount(&coupon, 1_000_000).unwrap(), 200_000);
+        assert_eq!(coupon.uses_remaining, 1);
+    }
+
+    #[test]
+    fn price_hold_is_honoured_until_it_expires() {
+        let hold = PriceReservation {
+            bonding_curve: Pubkey::new_unique(),
+            buyer: Pubkey::new_unique(),
+            price: 1_000_000,
+            expires_at: 500,
+            bump: 255,
+        };
+
+        // The curve moved up: the buyer pays the held price and the gap is recorded
+        assert_eq!(settle_price_hold(&hold, 1_200_000, 500).unwrap(), (1_000_000, 200_000));
+        // The curve moved down: the buyer pays the lower curve price
+        assert_eq!(settle_price_hold(&hold, 900_000, 400).unwrap(), (900_000, 0));
+        assert_eq!(
+            settle_price_hold(&hold, 1_200_000, 501).err(),
+            Some(anchor_lang::error::Error::from(BondingCurveError::ReservationExpired))
+        );
+    }
+
+    #[test]
+    fn reserve_mints_are_capped_and_leave_the_public_price_alone() {
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.base_price = 1_000_000;
+        curve.price_increment = 10_000;
+        curve.current_supply = 4;
+        curve.max_reserve_mints = 2;
+        let next_price = calculate_price(&curve, curve.current_supply + 1).unwrap();
+
+        record_reserve_mint(&mut curve).unwrap();
+        record_reserve_mint(&mut curve).unwrap();
+
+        assert_eq!(curve.reserved_minted, 2);
+        assert_eq!(curve.current_supply, 4);
+        assert_eq!(calculate_price(&curve, curve.current_supply + 1).unwrap(), next_price);
+        assert_eq!(
+            record_reserve_mint(&mut curve).err(),
+            Some(anchor_lang::error::Error::from(BondingCurveError::ReserveAllocationExceeded))
+        );
+    }
+
+    #[test]
+    fn buyback_runway_walks_down_the_curve() {
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.base_price = 1_000_000;
+        curve.price_increment = 100_000;
+        curve.current_supply = 5;
+        // Editions 1..=5 at 1.0, 1.1, 1.2, 1.3 and 1.4 million lamports
+        curve.buyback_liability = 6_000_000;
+
+        // The top two cost 1.4M + 1.3M; a third (1.2M) would overdraw
+        assert_eq!(buyback_runway(&curve, 2_700_000).unwrap(), 2);
+        assert_eq!(buyback_runway(&curve, 3_899_999).unwrap(), 2);
+        assert_eq!(buyback_runway(&curve, 3_900_000).unwrap(), 3);
+        // Payouts stop at what the sales kept, so the tracked liability covers them all
+        assert_eq!(buyback_runway(&curve, 6_000_000).unwrap(), 5);
+
+        // Editions sold below the curve cap the walk at their net proceeds
+        curve.buyback_liability = 3_000_000;
+        assert_eq!(buyback_runway(&curve, 2_999_999).unwrap(), 2);
+        assert_eq!(buyback_runway(&curve, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn sum_prices_matches_a_manual_sum() {
+        let manual = |curve: &BondingCurve, first: u32, last: u32| -> u64 {
+            (first..=last).map(|e| calculate_price(curve, e).unwrap()).sum()
+        };
+
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.base_price = 1_000;
+        curve.price_increment = 7;
+        assert_eq!(sum_prices(&curve, 1, 100).unwrap(), manual(&curve, 1, 100));
+        assert_eq!(sum_prices(&curve, 38, 38).unwrap(), manual(&curve, 38, 38));
+        assert_eq!(sum_prices(&curve, 40, 39).unwrap(), 0);
+
+        // Steepening, an offset and a flat tail each bend the linear sum
+        curve.steepen_at = 30;
+        curve.post_steepen_increment = 50;
+        curve.supply_offset = 5;
+        curve.flat_after = 60;
+        for (first, last) in [(1, 100), (1, 25), (20, 30), (26, 27), (45, 61), (62, 100)] {
+            assert_eq!(sum_prices(&curve, first, last).unwrap(), manual(&curve, first, last));
+        }
+
+        let mut step = blank_curve(CurveType::Step);
+        step.base_price = 500;
+        step.price_increment = 40;
+        step.tier_size = 7;
+        step.supply_offset = 3;
+        for (first, last) in [(1, 100), (5, 5), (4, 11), (13, 90)] {
+            assert_eq!(sum_prices(&step, first, last).unwrap(), manual(&step, first, last));
+        }
+
+        let mut constant = blank_curve(CurveType::Constant);
+        constant.base_price = 250;
+        assert_eq!(sum_prices(&constant, 1, 100).unwrap(), 25_000);
+
+        // The total raise at sellout is realized volume plus the unsold editions
+        curve.current_supply = 40;
+        curve.total_volume = manual(&curve, 1, 40);
+        assert_eq!(
+            curve.total_volume + sum_prices(&curve, 41, curve.max_supply).unwrap(),
+            manual(&curve, 1, 100)
+        );
+    }
+
+    #[test]
+    fn sum_prices_bounds_curves_without_a_closed_form() {
+        let mut curve = blank_curve(CurveType::Logarithmic);
+        curve.base_price = 1_000;
+        curve.price_increment = 100;
+        curve.max_supply = MAX_SUPPLY_CAP;
+        assert!(sum_prices(&curve, 1, MAX_PRICE_SUM_SPAN).is_ok());
+        assert_eq!(
+            sum_prices(&curve, 1, MAX_PRICE_SUM_SPAN + 1).unwrap_err(),
+            anchor_lang::error::Error::from(BondingCurveError::PriceSumTooLong)
+        );
+    }
+
+    #[test]
+    fn budget_quote_counts_affordable_editions() {
+        // 1_000, 1_010, 1_020, ... from edition 11: four editions cost 4_060
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.base_price = 900;
+        curve.price_increment = 10;
+        curve.current_supply = 10;
+        assert_eq!(budget_quote(&curve, 4_060).unwrap(), (4, 4_060));
+        assert_eq!(budget_quote(&curve, 5_099).unwrap(), (4, 4_060));
+        assert_eq!(budget_quote(&curve, 999).unwrap(), (0, 0));
+
+        // A budget past sellout stops at max_supply
+        let sellout = sum_prices(&curve, 11, 100).unwrap();
+        assert_eq!(budget_quote(&curve, u64::MAX).unwrap(), (90, sellout));
+
+        let mut sigmoid = blank_curve(CurveType::Sigmoid);
+        sigmoid.base_price = 1_000;
+        sigmoid.bezier_max_price = 10_000;
+        sigmoid.price_increment = 5;
+        let walked = budget_quote(&sigmoid, 20_000).unwrap();
+        assert_eq!(walked.1, sum_prices(&sigmoid, 1, walked.0).unwrap());
+        assert!(walked.1 + calculate_price(&sigmoid, walked.0 + 1).unwrap() > 20_000);
+    }
+
+    #[test]
+    fn auc_matches_total_volume_without_drift() {
+        let mut curve = blank_curve(CurveType::Step);
+        curve.base_price = 2_000;
+        curve.price_increment = 300;
+        curve.tier_size = 4;
+        for edition in 1..=37 {
+            curve.total_volume += calculate_price(&curve, edition).unwrap();
+            curve.current_supply = edition;
+        }
+        assert_eq!(sum_prices(&curve, 1, curve.current_supply).unwrap(), curve.total_volume);
+
+        // Repricing mid-sale shows up as drift
+        curve.price_increment = 400;
+        assert!(sum_prices(&curve, 1, curve.current_supply).unwrap() > curve.total_volume);
+    }
+
+    #[test]
+    fn cost_to_reach_sums_the_linear_climb() {
+        // Editions 21..=30 at 1_000 + 50 * (edition - 1): 10 * (2_000 + 2_450) / 2
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.base_price = 1_000;
+        curve.price_increment = 50;
+        curve.current_supply = 20;
+        assert_eq!(sum_prices(&curve, curve.current_supply + 1, 30).unwrap(), 22_250);
+        assert_eq!(sum_prices(&curve, curve.current_supply + 1, 21).unwrap(), 2_000);
+    }
+
+    #[test]
+    fn burn_share_rounds_down_per_edition() {
+        let mut curve = blank_curve(CurveType::Linear);
+        assert_eq!(burn_share(&curve, 1_000).unwrap(), 0);
+
+        curve.burn_bps = 250;
+        assert_eq!(burn_share(&curve, 1_000).unwrap(), 25);
+        assert_eq!(burn_share(&curve, 39).unwrap(), 0);
+
+        curve.burn_bps = 10_000;
+        assert_eq!(burn_share(&curve, u64::MAX).unwrap(), u64::MAX);
+
+        // Two editions burn no more than their combined price would
+        curve.burn_bps = 333;
+        let per_edition = burn_share(&curve, 101).unwrap() + burn_share(&curve, 103).unwrap();
+        assert_eq!(per_edition, 6);
+        assert_eq!(burn_share(&curve, 204).unwrap(), 6);
+    }
+
+    #[test]
+    fn required_reserve_scales_the_tracked_liability() {
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.buyback_liability = 1_000_000;
+        assert_eq!(required_reserve(&curve).unwrap(), 0);
+
+        curve.reserve_ratio = 2_500;
+        assert_eq!(required_reserve(&curve).unwrap(), 250_000);
+
+        // Live sell-backs need the whole liability regardless of the ratio
+        curve.sellback_enabled = true;
+        assert_eq!(required_reserve(&curve).unwrap(), 1_000_000);
+
+        curve.sellback_disabled = true;
+        assert_eq!(required_reserve(&curve).unwrap(), 250_000);
+
+        curve.reserve_ratio = 10_000;
+        curve.buyback_liability = u64::MAX;
+        assert_eq!(required_reserve(&curve).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn selling_back_refunds_the_top_price_and_drops_supply() {
+        let buyer = Pubkey::new_unique();
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.base_price = 1_000_000;
+        curve.price_increment = 100_000;
+        let mut receipts = Vec::new();
+        for edition in 1..=3 {
+            let price = calculate_price(&curve, edition).unwrap();
+            curve.current_supply = edition;
+            curve.total_volume += price;
+            curve.buyback_liability += price;
+            receipts.push(paid_receipt(buyer, edition, price, price, 0));
+        }
+
+        let last_price = calculate_price(&curve, 3).unwrap();
+        let refund = sellback_refund(&curve, &receipts[2]).unwrap();
+        assert_eq!(refund, last_price);
+
+        settle_sellback(&mut curve, &receipts[2], refund).unwrap();
+        assert_eq!(curve.current_supply, 2);
+        assert_eq!(curve.total_volume, 1_000_000 + 1_100_000);
+        assert_eq!(curve.buyback_liability, 1_000_000 + 1_100_000);
+
+        // The next edition sells at the price just refunded
+        assert_eq!(calculate_price(&curve, curve.current_supply + 1).unwrap(), last_price);
+    }
+
+    #[test]
+    fn recording_a_sale_past_u64_max_volume_overflows() {
+        let mut curve = blank_curve(CurveType::Constant);
+        curve.current_supply = 7;
+        curve.total_volume = u64::MAX - 10;
+        record_sale(&mut curve, 10).unwrap();
+        assert_eq!(curve.total_volume, u64::MAX);
+
+        let error = record_sale(&mut curve, 1).unwrap_err();
+        assert_eq!(error, anchor_lang::error::Error::from(BondingCurveError::ArithmeticOverflow));
+        // Nothing is counted when the volume can't be
+        assert_eq!(curve.current_supply, 8);
+    }
+
+    #[test]
+    fn batch_volume_is_the_sum_of_its_prices() {
+        let mut curve = blank_curve(CurveType::Linear);
+        curve.base_price = 1_000_000;
+        curve.price_increment = 25_000;
+        curve.edition_decimals = 2;
+        curve.current_supply = 12;
+        let quoted = batch_cost(&curve, 5).unwrap();
+
+        // Priced edition by edition, as mint_edition_batch walks the curve
+        let mut prices = Vec::new();
+        for _ in 0..5 {
+            let price = calculate_price(&curve, curve.current_supply + 1).unwrap();
+            record_sale(&mut curve, price).unwrap();
+            prices.push(price);
+        }
+
+        assert_eq!(curve.current_supply, 17);
+        assert_eq!(curve.total_volume, prices.iter().sum::<u64>());
+        assert_eq!(curve.total_volume, quoted);
+    }
+}
@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
 use anchor_spl::associated_token::AssociatedToken;
 use mpl_token_metadata::{
-    instructions::{CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs, CreateMasterEditionV3, CreateMasterEditionV3InstructionArgs},
+    accounts::Metadata,
+    instructions::{CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs, CreateMasterEditionV3, CreateMasterEditionV3InstructionArgs, SetAndVerifyCollection, UnverifyCollection, VerifyCollection},
     types::{DataV2, Creator},
 };
 
@@ -18,7 +19,13 @@ pub mod bonding_curve {
         base_price: u64,
         price_increment: u64,
         max_supply: u32,
+        uri_template: String,
     ) -> Result<()> {
+        require!(
+            uri_template.len() <= mpl_token_metadata::MAX_URI_LENGTH,
+            BondingCurveError::InvalidUriTemplate
+        );
+
         let curve = &mut ctx.accounts.bonding_curve;
         
         curve.authority = ctx.accounts.authority.key();
@@ -29,11 +36,20 @@ pub mod bonding_curve {
         curve.current_supply = 0;
         curve.total_volume = 0;
         curve.bump = ctx.bumps.bonding_curve;
+        curve.uri_template = uri_template;
+
+        // One-time check of the collection's update authority, cached for verification CPIs
+        curve.collection_authority = read_collection_authority(
+            &ctx.accounts.collection_metadata,
+            &curve.collection_mint,
+        )?;
 
         Ok(())
     }
 
     /// Mint a new edition with linear bonding curve pricing + Metaplex metadata
+    /// The curve's URI template, when set, overrides `uri` with a per-edition URI
+    /// The metadata name is `{name} #{edition}`, with `name` truncated to fit Metaplex's limit
     pub fn mint_edition(
         ctx: Context<MintEdition>,
         name: String,
@@ -100,6 +116,17 @@ pub mod bonding_curve {
         token::mint_to(cpi_ctx, 1)?;
 
 
+        // Per-edition URI from the curve's template, falling back to the caller's URI
+        let uri = if curve.uri_template.is_empty() {
+            uri
+        } else {
+            edition_uri(&curve.uri_template, curve.current_supply + 1)
+        };
+        require!(
+            uri.len() <= mpl_token_metadata::MAX_URI_LENGTH,
+            BondingCurveError::InvalidUriTemplate
+        );
+
         // Create Metaplex metadata account
         let creator = Creator {
             address: authority,
@@ -108,9 +135,9 @@ pub mod bonding_curve {
         };
 
         let data_v2 = DataV2 {
-            name: name.clone(),
+            name: edition_name(&name, curve.current_supply + 1),
             symbol: symbol.clone(),
-            uri: uri.clone(),
+            uri,
             seller_fee_basis_points,
             creators: Some(vec![creator]),
             collection: Some(mpl_token_metadata::types::Collection {
@@ -186,17 +213,49 @@ pub mod bonding_curve {
             signer,
         )?;
 
+        // Verify the edition into the collection, signed by the curve PDA as collection authority
+        require_keys_eq!(
+            curve.collection_authority,
+            bonding_curve_key,
+            BondingCurveError::CollectionAuthorityMismatch
+        );
+
+        let verify_collection_ix = VerifyCollection {
+            metadata: ctx.accounts.edition_metadata.key(),
+            collection_authority: bonding_curve_key,
+            payer: ctx.accounts.buyer.key(),
+            collection_mint: ctx.accounts.collection_mint.key(),
+            collection: ctx.accounts.collection_metadata.key(),
+            collection_master_edition_account: ctx.accounts.collection_master_edition.key(),
+            collection_authority_record: None,
+        }.instruction();
+
+        anchor_lang::solana_program::program::invoke_signed(
+            &verify_collection_ix,
+            &[
+                ctx.accounts.edition_metadata.to_account_info(),
+                bonding_curve_info.clone(),
+                ctx.accounts.buyer.to_account_info(),
+                ctx.accounts.collection_mint.to_account_info(),
+                ctx.accounts.collection_metadata.to_account_info(),
+                ctx.accounts.collection_master_edition.to_account_info(),
+            ],
+            signer,
+        )?;
+
         // Update curve state
         curve.current_supply += 1;
-        curve.total_volume += current_price;
+        curve.total_volume = curve.total_volume
+            .checked_add(current_price)
+            .ok_or(BondingCurveError::ArithmeticOverflow)?;
 
         Ok(())
     }
 
-    /// Get current price for next edition
+    /// Get current price for next edition (returned via return data, readable by simulation)
     pub fn get_price(
         ctx: Context<GetPrice>,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         let curve = &ctx.accounts.bonding_curve;
         
         let price = curve.base_price
@@ -207,6 +266,97 @@ pub mod bonding_curve {
             )
             .ok_or(BondingCurveError::ArithmeticOverflow)?;
         
+        Ok(price)
+    }
+
+    /// Re-point a minted edition at a new collection and verify it there
+    /// Signed by the collection authority; unverifies the old collection first if needed
+    pub fn set_edition_collection(
+        ctx: Context<SetEditionCollection>,
+    ) -> Result<()> {
+        let metadata = Metadata::from_bytes(&ctx.accounts.edition_metadata.try_borrow_data()?)
+            .map_err(|_| BondingCurveError::InvalidEditionMetadata)?;
+
+        require_keys_eq!(
+            metadata.mint,
+            ctx.accounts.edition_mint.key(),
+            BondingCurveError::InvalidEditionMetadata
+        );
+
+        // Drop verification on the old collection before moving the edition
+        if let Some(old_collection) = metadata.collection {
+            if old_collection.verified {
+                require_keys_eq!(
+                    old_collection.key,
+                    ctx.accounts.old_collection_mint.key(),
+                    BondingCurveError::InvalidEditionMetadata
+                );
+
+                let unverify_ix = UnverifyCollection {
+                    metadata: ctx.accounts.edition_metadata.key(),
+                    collection_authority: ctx.accounts.collection_authority.key(),
+                    collection_mint: ctx.accounts.old_collection_mint.key(),
+                    collection: ctx.accounts.old_collection_metadata.key(),
+                    collection_master_edition_account: ctx.accounts.old_collection_master_edition.key(),
+                    collection_authority_record: None,
+                }.instruction();
+
+                anchor_lang::solana_program::program::invoke(
+                    &unverify_ix,
+                    &[
+                        ctx.accounts.edition_metadata.to_account_info(),
+                        ctx.accounts.collection_authority.to_account_info(),
+                        ctx.accounts.old_collection_mint.to_account_info(),
+                        ctx.accounts.old_collection_metadata.to_account_info(),
+                        ctx.accounts.old_collection_master_edition.to_account_info(),
+                    ],
+                )?;
+            }
+        }
+
+        // Set the new collection and verify it in one CPI
+        let set_and_verify_ix = SetAndVerifyCollection {
+            metadata: ctx.accounts.edition_metadata.key(),
+            collection_authority: ctx.accounts.collection_authority.key(),
+            payer: ctx.accounts.collection_authority.key(),
+            update_authority: ctx.accounts.bonding_curve.key(),
+            collection_mint: ctx.accounts.new_collection_mint.key(),
+            collection: ctx.accounts.new_collection_metadata.key(),
+            collection_master_edition_account: ctx.accounts.new_collection_master_edition.key(),
+            collection_authority_record: None,
+        }.instruction();
+
+        anchor_lang::solana_program::program::invoke(
+            &set_and_verify_ix,
+            &[
+                ctx.accounts.edition_metadata.to_account_info(),
+                ctx.accounts.collection_authority.to_account_info(),
+                ctx.accounts.collection_authority.to_account_info(),
+                ctx.accounts.bonding_curve.to_account_info(),
+                ctx.accounts.new_collection_mint.to_account_info(),
+                ctx.accounts.new_collection_metadata.to_account_info(),
+                ctx.accounts.new_collection_master_edition.to_account_info(),
+            ],
+        )?;
+
+        msg!("Edition {} moved to collection {}", ctx.accounts.edition_mint.key(), ctx.accounts.new_collection_mint.key());
+
+        Ok(())
+    }
+
+    /// Re-read the collection's update authority after it changed (authority only)
+    pub fn revalidate_collection_authority(
+        ctx: Context<RevalidateCollectionAuthority>,
+    ) -> Result<()> {
+        let curve = &mut ctx.accounts.bonding_curve;
+
+        curve.collection_authority = read_collection_authority(
+            &ctx.accounts.collection_metadata,
+            &curve.collection_mint,
+        )?;
+
+        msg!("Collection authority revalidated: {}", curve.collection_authority);
+
         Ok(())
     }
 
@@ -225,12 +375,43 @@ pub mod bonding_curve {
     }
 }
 
+// Metadata name for an edition: `{base} #{edition}`, cutting the base name
+// (on a char boundary) so the whole name fits MAX_NAME_LENGTH
+fn edition_name(base: &str, edition: u32) -> String {
+    let suffix = format!(" #{}", edition);
+    let mut end = base.len().min(mpl_token_metadata::MAX_NAME_LENGTH.saturating_sub(suffix.len()));
+    while !base.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!("{}{}", base[..end].trim_end(), suffix)
+}
+
+// Metadata URI for an edition: every `{id}` in the template becomes the edition number
+fn edition_uri(template: &str, edition: u32) -> String {
+    template.replace("{id}", &edition.to_string())
+}
+
+// Update authority of the collection, read from its Metaplex metadata
+fn read_collection_authority(collection_metadata: &AccountInfo, collection_mint: &Pubkey) -> Result<Pubkey> {
+    let metadata = Metadata::from_bytes(&collection_metadata.try_borrow_data()?)
+        .map_err(|_| BondingCurveError::InvalidEditionMetadata)?;
+
+    require_keys_eq!(
+        metadata.mint,
+        *collection_mint,
+        BondingCurveError::InvalidEditionMetadata
+    );
+
+    Ok(metadata.update_authority)
+}
+
 #[derive(Accounts)]
 pub struct InitializeCurve<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 32 + 32 + 8 + 8 + 4 + 4 + 8 + 1,
+        space = 8 + 32 + 32 + 8 + 8 + 4 + 4 + 8 + 1 + 32 + (4 + mpl_token_metadata::MAX_URI_LENGTH),
         seeds = [b"bonding_curve", collection_mint.key().as_ref()],
         bump
     )]
@@ -239,6 +420,10 @@ pub struct InitializeCurve<'info> {
     /// CHECK: Collection mint address
     pub collection_mint: AccountInfo<'info>,
     
+    /// CHECK: Collection metadata (validated against collection_mint)
+    #[account(owner = mpl_token_metadata::ID)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -274,8 +459,15 @@ pub struct MintEdition<'info> {
     pub edition_master_edition: UncheckedAccount<'info>,
     
     /// CHECK: Collection mint for metadata
+    #[account(constraint = collection_mint.key() == bonding_curve.collection_mint)]
     pub collection_mint: AccountInfo<'info>,
     
+    /// CHECK: Collection metadata (verified against by Metaplex)
+    pub collection_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Collection master edition (verified against by Metaplex)
+    pub collection_master_edition: UncheckedAccount<'info>,
+    
     #[account(mut)]
     pub buyer: Signer<'info>,
     
@@ -316,6 +508,69 @@ pub struct CloseCurve<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct SetEditionCollection<'info> {
+    #[account(
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    pub edition_mint: Account<'info, Mint>,
+    
+    /// CHECK: Edition metadata (validated against edition_mint)
+    #[account(mut, owner = mpl_token_metadata::ID)]
+    pub edition_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Collection the edition is currently part of
+    pub old_collection_mint: AccountInfo<'info>,
+    
+    /// CHECK: Metadata of the current collection
+    #[account(mut)]
+    pub old_collection_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Master edition of the current collection
+    pub old_collection_master_edition: UncheckedAccount<'info>,
+    
+    /// CHECK: Collection the edition moves to
+    pub new_collection_mint: AccountInfo<'info>,
+    
+    /// CHECK: Metadata of the new collection
+    #[account(mut)]
+    pub new_collection_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Master edition of the new collection
+    pub new_collection_master_edition: UncheckedAccount<'info>,
+    
+    /// Cached collection update authority (revalidate if it changed)
+    #[account(
+        mut,
+        constraint = collection_authority.key() == bonding_curve.collection_authority @ BondingCurveError::CollectionAuthorityMismatch
+    )]
+    pub collection_authority: Signer<'info>,
+    
+    /// CHECK: Metaplex Token Metadata Program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevalidateCollectionAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", bonding_curve.collection_mint.as_ref()],
+        bump = bonding_curve.bump,
+        constraint = bonding_curve.authority == authority.key() @ BondingCurveError::Unauthorized
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// CHECK: Collection metadata (validated against the curve's collection mint)
+    #[account(owner = mpl_token_metadata::ID)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+}
+
 #[account]
 pub struct BondingCurve {
     pub authority: Pubkey,          // 32
@@ -326,6 +581,8 @@ pub struct BondingCurve {
     pub current_supply: u32,        // 4
     pub total_volume: u64,          // 8
     pub bump: u8,                   // 1
+    pub collection_authority: Pubkey, // 32 (cached from collection metadata)
+    pub uri_template: String,       // 4 + 200 (`{id}` replaced by the edition number; empty = caller's URI)
 }
 
 #[error_code]
@@ -338,4 +595,10 @@ pub enum BondingCurveError {
     CurveNotEmpty,
     #[msg("Arithmetic overflow in price calculation")]
     ArithmeticOverflow,
+    #[msg("Edition metadata does not match the edition or its collection")]
+    InvalidEditionMetadata,
+    #[msg("Collection authority does not match the cached one; revalidate it")]
+    CollectionAuthorityMismatch,
+    #[msg("URI template or resolved edition URI exceeds the Metaplex URI length")]
+    InvalidUriTemplate,
 }